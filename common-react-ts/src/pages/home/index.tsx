@@ -1,9 +0,0 @@
-import React from 'react';
-
-export default function Home() {
-  return (
-    <div>
-      <h1>This is Home Page</h1>
-    </div>
-  );
-}
\ No newline at end of file
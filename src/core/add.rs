@@ -0,0 +1,489 @@
+use anyhow::{bail, Ok, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use crate::utils::error::ElzaError;
+use crate::utils::logger;
+
+use super::build::{render_project, value_name, write_project_file, InlineConfig};
+use super::cli::{CssPreset, FrameWork, JsLoader, StateManagement, UIDesign, UtilityCss};
+use super::detect::detect_config;
+use super::elzarc::{hash_content, update_base, ElzaRc, BASE_DIR};
+use super::manifest::TemplateManifest;
+use super::pack::PackTool;
+use super::package_json::PackageJson;
+use super::package_manager::PackageManager;
+
+// add 命令的可选参数，未指定的配置保持不变
+#[derive(Clone, Copy, Debug, Default)]
+pub struct AddOptions {
+    pub loader: Option<JsLoader>,
+    pub css: Option<CssPreset>,
+    pub utility: Option<UtilityCss>,
+    pub state: Option<StateManagement>,
+    pub ui: Option<UIDesign>,
+    // 覆盖已被修改过的文件
+    pub force: bool,
+}
+
+// 为已有项目添加功能：按新旧配置分别渲染模板，只更新两者不同的文件和依赖
+pub fn add_features(project_dir: &Path, options: AddOptions) -> Result<()> {
+    let mut pj = PackageJson::new(project_dir)?;
+    // 优先使用生成时记录的配置，旧项目再根据依赖及配置文件推断
+    let mut rc = ElzaRc::read(project_dir)?;
+    let current = match &rc {
+        Some(rc) if rc.source.is_some() => bail!(ElzaError::InvalidCombination(t!(
+            "add.external_unsupported"
+        ))),
+        Some(rc) => rc.config,
+        None => detect_config(project_dir, &pj)?,
+    };
+    logger::info(&t!(
+        "add.detected",
+        packTool = current.pack_tool,
+        frame = current.frame,
+        lang = value_name(&current.lang)
+    ));
+    let next = apply_options(current, options)?;
+    if next == current {
+        logger::info(&t!("add.nothing_to_do"));
+        return Ok(());
+    }
+
+    let manifest = TemplateManifest::find(current.frame, current.pack_tool, current.lang)?;
+    let project_name = pj.name().to_string();
+    let old_files = render_project(
+        &manifest,
+        current,
+        &manifest.get_context(&current, &project_name)?,
+    )?;
+    let new_files = render_project(
+        &manifest,
+        next,
+        &manifest.get_context(&next, &project_name)?,
+    )?;
+    // 先在内存中完成全部文件的合并，存在无法合并的文件时不做任何修改
+    let (patches, conflicts) = plan_patches(
+        project_dir,
+        rc.as_ref(),
+        &old_files,
+        &new_files,
+        options.force,
+    );
+    if !conflicts.is_empty() {
+        for conflict in &conflicts {
+            report_conflict(conflict);
+        }
+        bail!(ElzaError::Conflict(t!(
+            "add.conflicts",
+            count = conflicts.len()
+        )));
+    }
+    apply_patches(project_dir, &patches)?;
+
+    // 依赖只增删新旧配置之间的差异，保留用户已有的版本
+    let old_deps = manifest.get_dependencies(&current)?;
+    let new_deps = manifest.get_dependencies(&next)?;
+    for dep in &old_deps {
+        if !new_deps.iter().any(|d| d.name == dep.name) {
+            pj.remove_dependency(&dep.name);
+            if let Some(rc) = &mut rc {
+                rc.dependencies.remove(&dep.name);
+            }
+        }
+    }
+    for dep in &new_deps {
+        if !old_deps.iter().any(|d| d.name == dep.name) {
+            pj.update_dependencies(&dep.name, &dep.version, dep.mod_type)?;
+            if let Some(rc) = &mut rc {
+                rc.dependencies
+                    .insert(dep.name.clone(), dep.version.clone());
+            }
+        }
+    }
+    pj.sort();
+    pj.write()?;
+    if let Some(rc) = &mut rc {
+        update_rc(rc, project_dir, next, &old_files, &new_files)?;
+    }
+
+    logger::ready(&t!("add.done"));
+    logger::full_info(&PackageManager::detect(None).install_command());
+    Ok(())
+}
+
+fn apply_options(current: InlineConfig, options: AddOptions) -> Result<InlineConfig> {
+    let mut next = current;
+    if let Some(loader) = options.loader {
+        if current.pack_tool != PackTool::Webpack {
+            bail!(ElzaError::InvalidCombination(t!(
+                "add.loader_unsupported",
+                packTool = current.pack_tool
+            )));
+        }
+        next.loader = loader;
+    }
+    if (options.css.is_some() || options.utility.is_some()) && current.pack_tool == PackTool::Elza {
+        bail!(ElzaError::InvalidCombination(t!(
+            "add.elza_css_unsupported"
+        )));
+    }
+    if let Some(css) = options.css {
+        if css.is_css_in_js() && current.frame != FrameWork::React {
+            bail!(ElzaError::InvalidCombination(t!(
+                "add.css_unsupported",
+                frame = current.frame,
+                css = css
+            )));
+        }
+        next.css = css;
+    }
+    if let Some(utility) = options.utility {
+        next.utility = utility;
+    }
+    if (options.state.is_some() || options.ui.is_some()) && current.frame != FrameWork::React {
+        bail!(ElzaError::InvalidCombination(t!(
+            "add.react_only",
+            frame = current.frame
+        )));
+    }
+    if let Some(state) = options.state {
+        next.state = state;
+    }
+    if let Some(ui) = options.ui {
+        next.ui = ui;
+    }
+    Ok(next)
+}
+
+enum Patch {
+    Write(Vec<u8>),
+    Delete,
+}
+
+// 无法自动合并的文件及对应的模板改动
+struct Conflict {
+    path: String,
+    old: Option<Vec<u8>>,
+    new: Option<Vec<u8>>,
+}
+
+// 磁盘上的文件与旧配置的渲染结果或生成记录一致时直接更新，被用户修改过的文件以旧配置的渲染结果为基准三方合并
+fn plan_patches(
+    project_dir: &Path,
+    rc: Option<&ElzaRc>,
+    old_files: &BTreeMap<String, Vec<u8>>,
+    new_files: &BTreeMap<String, Vec<u8>>,
+    force: bool,
+) -> (Vec<(String, Patch)>, Vec<Conflict>) {
+    let mut patches = vec![];
+    let mut conflicts = vec![];
+    let paths: BTreeSet<&String> = old_files.keys().chain(new_files.keys()).collect();
+    for path in paths {
+        let old = old_files.get(path);
+        let new = new_files.get(path);
+        if old == new {
+            continue;
+        }
+        let disk = fs::read(project_dir.join(path)).ok();
+        if disk.as_ref() == new {
+            continue;
+        }
+        let unmodified = disk.as_ref() == old
+            || disk
+                .as_ref()
+                .zip(rc)
+                .is_some_and(|(data, rc)| rc.is_unmodified(path, data));
+        let patch = match (new, disk) {
+            (Some(data), _) if unmodified || force => Patch::Write(data.clone()),
+            (None, _) if unmodified || force => Patch::Delete,
+            (Some(data), Some(disk)) => match merge(old, &disk, data) {
+                Some(merged) => {
+                    logger::info(&t!("add.merged", path = path));
+                    Patch::Write(merged)
+                }
+                None => {
+                    conflicts.push(Conflict {
+                        path: path.clone(),
+                        old: old.cloned(),
+                        new: new.cloned(),
+                    });
+                    continue;
+                }
+            },
+            // 用户已删除的文件保持删除
+            (Some(_), None) => continue,
+            (None, _) => {
+                conflicts.push(Conflict {
+                    path: path.clone(),
+                    old: old.cloned(),
+                    new: None,
+                });
+                continue;
+            }
+        };
+        patches.push((path.clone(), patch));
+    }
+    (patches, conflicts)
+}
+
+// 三方合并文本文件，存在冲突或无法按文本处理时返回 None
+fn merge(base: Option<&Vec<u8>>, ours: &[u8], theirs: &[u8]) -> Option<Vec<u8>> {
+    let base = std::str::from_utf8(base.map(Vec::as_slice).unwrap_or_default()).ok()?;
+    let ours = std::str::from_utf8(ours).ok()?;
+    let theirs = std::str::from_utf8(theirs).ok()?;
+    diffy::merge(base, ours, theirs)
+        .ok()
+        .map(String::into_bytes)
+}
+
+fn apply_patches(project_dir: &Path, patches: &[(String, Patch)]) -> Result<()> {
+    for (path, patch) in patches {
+        match patch {
+            Patch::Write(data) => write_project_file(project_dir, path, data)?,
+            Patch::Delete => {
+                logger::debug(&t!("file.delete", path = path));
+                let file_path = project_dir.join(path);
+                if file_path.exists() {
+                    fs::remove_file(&file_path)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// 输出需要手动完成的修改
+fn report_conflict(conflict: &Conflict) {
+    let path = &conflict.path;
+    let texts = [&conflict.old, &conflict.new].map(|data| {
+        std::str::from_utf8(data.as_deref().unwrap_or_default())
+            .ok()
+            .map(str::to_string)
+    });
+    match (&conflict.new, texts) {
+        (None, _) => logger::warning(&t!("add.conflict_removed", path = path)),
+        (Some(_), [Some(old), Some(new)]) => {
+            logger::warning(&t!("add.conflict", path = path));
+            logger::notice(&diffy::create_patch(&old, &new).to_string());
+        }
+        (Some(_), _) => logger::warning(&t!("add.conflict_binary", path = path)),
+    }
+}
+
+// 记录新的配置，并同步已更新文件的摘要及合并基准
+fn update_rc(
+    rc: &mut ElzaRc,
+    project_dir: &Path,
+    config: InlineConfig,
+    old_files: &BTreeMap<String, Vec<u8>>,
+    new_files: &BTreeMap<String, Vec<u8>>,
+) -> Result<()> {
+    rc.config = config;
+    let has_base = project_dir.join(BASE_DIR).is_dir();
+    let paths: BTreeSet<&String> = old_files.keys().chain(new_files.keys()).collect();
+    for path in paths {
+        let new = new_files.get(path);
+        if old_files.get(path) == new {
+            continue;
+        }
+        match new {
+            Some(data) => rc.files.insert(path.clone(), hash_content(data)),
+            None => rc.files.remove(path),
+        };
+        if has_base {
+            update_base(project_dir, path, new.map(Vec::as_slice))?;
+        }
+    }
+    rc.record_file(project_dir, "package.json")?;
+    rc.write(project_dir)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cli::{CodeLanguage, StateManagement, UIDesign, UtilityCss};
+    use crate::core::elzarc::write_base;
+    use crate::utils::test_utils::TempDir;
+
+    const WEBPACK_COMMON: &str = "scripts/webpack.common.ts";
+
+    fn files(entries: &[(&str, &str)]) -> BTreeMap<String, Vec<u8>> {
+        entries
+            .iter()
+            .map(|(path, content)| (path.to_string(), content.as_bytes().to_vec()))
+            .collect()
+    }
+
+    fn written(patches: &[(String, Patch)], path: &str) -> Option<String> {
+        patches
+            .iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, patch)| match patch {
+                Patch::Write(data) => String::from_utf8(data.clone()).unwrap(),
+                Patch::Delete => "<deleted>".to_string(),
+            })
+    }
+
+    #[test]
+    fn plans_unmodified_merged_and_conflicting_files() {
+        let dir = TempDir::new();
+        let old = files(&[
+            ("same.txt", "a\nb\nc\n"),
+            ("edited.txt", "one\ntwo\nthree\nfour\nfive\n"),
+            ("conflict.txt", "one\ntwo\nthree\n"),
+            ("removed.txt", "old\n"),
+        ]);
+        let new = files(&[
+            ("same.txt", "a\nB\nc\n"),
+            ("edited.txt", "one\nTWO\nthree\nfour\nfive\n"),
+            ("conflict.txt", "one\nTWO\nthree\n"),
+        ]);
+        dir.write("same.txt", "a\nb\nc\n");
+        dir.write("edited.txt", "one\ntwo\nthree\nfour\nfive\nsix\n");
+        dir.write("conflict.txt", "one\nmine\nthree\n");
+        dir.write("removed.txt", "old\n");
+
+        let (patches, conflicts) = plan_patches(dir.path(), None, &old, &new, false);
+        assert_eq!(written(&patches, "same.txt").unwrap(), "a\nB\nc\n");
+        assert_eq!(
+            written(&patches, "edited.txt").unwrap(),
+            "one\nTWO\nthree\nfour\nfive\nsix\n"
+        );
+        assert_eq!(written(&patches, "removed.txt").unwrap(), "<deleted>");
+        assert!(written(&patches, "conflict.txt").is_none());
+        let conflicted: Vec<&str> = conflicts.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(conflicted, ["conflict.txt"]);
+    }
+
+    #[test]
+    fn force_overwrites_conflicting_files() {
+        let dir = TempDir::new();
+        let old = files(&[("conflict.txt", "one\ntwo\n")]);
+        let new = files(&[("conflict.txt", "one\nTWO\n")]);
+        dir.write("conflict.txt", "one\nmine\n");
+
+        let (patches, conflicts) = plan_patches(dir.path(), None, &old, &new, true);
+        assert!(conflicts.is_empty());
+        assert_eq!(written(&patches, "conflict.txt").unwrap(), "one\nTWO\n");
+    }
+
+    fn sass_config() -> InlineConfig {
+        InlineConfig {
+            frame: FrameWork::React,
+            pack_tool: PackTool::Webpack,
+            lang: CodeLanguage::Ts,
+            loader: JsLoader::Babel,
+            ui: UIDesign::None,
+            state: StateManagement::None,
+            css: CssPreset::Sass,
+            utility: UtilityCss::None,
+        }
+    }
+
+    fn render(config: InlineConfig) -> BTreeMap<String, Vec<u8>> {
+        let manifest = TemplateManifest::find(config.frame, config.pack_tool, config.lang).unwrap();
+        let context = manifest.get_context(&config, "demo").unwrap();
+        render_project(&manifest, config, &context).unwrap()
+    }
+
+    // 以指定配置生成项目文件及依赖，with_rc 为 false 时模拟没有 .elzarc.json 的旧项目
+    fn generate(dir: &TempDir, config: InlineConfig, with_rc: bool) {
+        let manifest = TemplateManifest::find(config.frame, config.pack_tool, config.lang).unwrap();
+        let files = render(config);
+        for (path, data) in &files {
+            dir.write(path, data);
+        }
+        let dependencies = manifest.get_dependencies(&config).unwrap();
+        let mut pj = PackageJson::new(dir.path()).unwrap();
+        for dep in &dependencies {
+            pj.update_dependencies(&dep.name, &dep.version, dep.mod_type)
+                .unwrap();
+        }
+        pj.write().unwrap();
+        if with_rc {
+            let mut rc = ElzaRc::new(config, &manifest, &files, &dependencies).unwrap();
+            rc.record_file(dir.path(), "package.json").unwrap();
+            rc.write(dir.path()).unwrap();
+            write_base(dir.path(), &files).unwrap();
+        }
+    }
+
+    fn less_options(force: bool) -> AddOptions {
+        AddOptions {
+            css: Some(CssPreset::Less),
+            force,
+            ..AddOptions::default()
+        }
+    }
+
+    #[test]
+    fn conflict_aborts_before_writing_anything() {
+        let dir = TempDir::new();
+        generate(&dir, sass_config(), false);
+        let edited = dir
+            .read(WEBPACK_COMMON)
+            .unwrap()
+            .replace("'sass-loader',", "'sass-loader', // mine");
+        dir.write(WEBPACK_COMMON, &edited);
+        let package_json = dir.read("package.json").unwrap();
+
+        let err = add_features(dir.path(), less_options(false)).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ElzaError>(),
+            Some(ElzaError::Conflict(_))
+        ));
+        assert_eq!(dir.read(WEBPACK_COMMON).unwrap(), edited);
+        assert_eq!(dir.read("package.json").unwrap(), package_json);
+    }
+
+    #[test]
+    fn force_replaces_conflicting_files() {
+        let dir = TempDir::new();
+        generate(&dir, sass_config(), false);
+        let edited = dir
+            .read(WEBPACK_COMMON)
+            .unwrap()
+            .replace("'sass-loader',", "'sass-loader', // mine");
+        dir.write(WEBPACK_COMMON, &edited);
+
+        add_features(dir.path(), less_options(true)).unwrap();
+        let expected = render(InlineConfig {
+            css: CssPreset::Less,
+            ..sass_config()
+        });
+        assert_eq!(
+            dir.read(WEBPACK_COMMON).unwrap().as_bytes(),
+            expected[WEBPACK_COMMON]
+        );
+        let pj = PackageJson::new(dir.path()).unwrap();
+        assert!(pj.has_dependency("less-loader"));
+        assert!(!pj.has_dependency("sass-loader"));
+    }
+
+    #[test]
+    fn merges_edits_and_updates_rc() {
+        let dir = TempDir::new();
+        generate(&dir, sass_config(), true);
+        let edited = dir.read(WEBPACK_COMMON).unwrap() + "// mine\n";
+        dir.write(WEBPACK_COMMON, &edited);
+
+        add_features(dir.path(), less_options(false)).unwrap();
+        let merged = dir.read(WEBPACK_COMMON).unwrap();
+        assert!(merged.contains("'less-loader'") && !merged.contains("'sass-loader'"));
+        assert!(merged.ends_with("// mine\n"));
+
+        let next = InlineConfig {
+            css: CssPreset::Less,
+            ..sass_config()
+        };
+        let expected = render(next);
+        let rc = ElzaRc::read(dir.path()).unwrap().unwrap();
+        assert_eq!(rc.config, next);
+        assert!(rc.dependencies.contains_key("less-loader"));
+        assert!(rc.is_unmodified(WEBPACK_COMMON, &expected[WEBPACK_COMMON]));
+        let base = fs::read(dir.path().join(BASE_DIR).join(WEBPACK_COMMON)).unwrap();
+        assert_eq!(base, expected[WEBPACK_COMMON]);
+    }
+}
@@ -1,442 +1,432 @@
-use anyhow::{Ok, Result};
-use rust_embed::{EmbeddedFile, RustEmbed};
-use std::path::PathBuf;
-use std::process::{exit, Command, Stdio};
-use std::{fs, vec};
-
-use crate::utils::error::{handle_option, handle_result};
+use anyhow::{bail, Context, Ok, Result};
+use clap::ValueEnum;
+use rust_embed::RustEmbed;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use crate::utils::error::ElzaError;
 use crate::utils::logger;
 
-use super::cli::{CodeLanguage, CssPreset, Dependency, FrameWork, JsLoader};
+use super::cli::{
+    CodeLanguage, CssPreset, FrameWork, JsLoader, StateManagement, UIDesign, UtilityCss,
+};
+use super::dry_run::print_plan;
+use super::elzarc::{write_base, ElzaRc};
+use super::manifest::{Dependency, Layer, TemplateManifest};
 use super::pack::PackTool;
 use super::package_json::{PackageBasicInfo, PackageJson};
-
-#[derive(Clone, Copy)]
+use super::package_manager::PackageManager;
+use super::resolve::resolve_latest_versions;
+use super::source::{list_files, TEMPLATE_MANIFEST_FILE};
+use super::template::{render, TemplateContext, TemplateValue};
+use super::transaction::ProjectTransaction;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct InlineConfig {
     pub frame: FrameWork,
     pub pack_tool: PackTool,
     pub lang: CodeLanguage,
     pub loader: JsLoader,
-    // pub ui: UIDesign,
-    // pub state: StateManagement,
+    pub ui: UIDesign,
+    pub state: StateManagement,
     pub css: CssPreset,
+    pub utility: UtilityCss,
 }
 
-#[derive(RustEmbed)]
-#[folder = "common/"]
-struct Common;
-
-#[derive(RustEmbed)]
-#[folder = "common-react-js"]
-struct CommonReactJs;
-
-#[derive(RustEmbed)]
-#[folder = "common-react-ts"]
-struct CommonReactTs;
-
-#[derive(RustEmbed)]
-#[folder = "react/webpack/template-js"]
-struct WebpackReactJsTemplate;
-
-#[derive(RustEmbed)]
-#[folder = "react/webpack/template-ts"]
-struct WebpackReactTsTemplate;
-
-#[derive(RustEmbed)]
-#[folder = "react/vite/template-js"]
-struct ViteReactJsTemplate;
-
-#[derive(RustEmbed)]
-#[folder = "react/vite/template-ts"]
-struct ViteReactTsTemplate;
-
-#[derive(RustEmbed)]
-#[folder = "react/rspack/template-js"]
-struct RspackReactJsTemplate;
-
-#[derive(RustEmbed)]
-#[folder = "react/rspack/template-ts"]
-struct RspackReactTsTemplate;
+// 可在模板清单条件及模板中使用的配置项
+const CONFIG_VARS: [&str; 8] = [
+    "frame", "packTool", "lang", "loader", "css", "utility", "state", "ui",
+];
+
+impl InlineConfig {
+    // 模板清单条件中使用的变量
+    pub fn get_var(&self, key: &str) -> Option<String> {
+        let value = match key {
+            "frame" => value_name(&self.frame),
+            "packTool" => value_name(&self.pack_tool),
+            "lang" => value_name(&self.lang),
+            "loader" => value_name(&self.loader),
+            "css" => value_name(&self.css),
+            "utility" => value_name(&self.utility),
+            "state" => value_name(&self.state),
+            "ui" => value_name(&self.ui),
+            _ => return None,
+        };
+        Some(value)
+    }
 
-#[derive(RustEmbed)]
-#[folder = "react/farm"]
-struct FarmReactTemplate;
+    pub fn to_context(self) -> TemplateContext {
+        CONFIG_VARS
+            .iter()
+            .filter_map(|key| Some((key.to_string(), TemplateValue::Str(self.get_var(key)?))))
+            .collect()
+    }
+}
 
-#[derive(RustEmbed)]
-#[folder = "react/elza/template-js"]
-struct ElzaReactJsTemplate;
+// 取参数名作为变量值，被 skip 的 None 变体统一记为 none
+pub fn value_name<T: ValueEnum>(value: &T) -> String {
+    value
+        .to_possible_value()
+        .map(|v| v.get_name().to_string())
+        .unwrap_or_else(|| "none".to_string())
+}
 
+// 所有内置模板及模板清单
 #[derive(RustEmbed)]
-#[folder = "react/elza/template-ts"]
-struct ElzaReactTsTemplate;
+#[folder = "templates/"]
+pub struct Templates;
+
+// 用户模板覆盖目录的环境变量，未设置时使用 ~/.elza/templates
+pub const TEMPLATE_OVERLAY_ENV: &str = "ELZA_TEMPLATE_DIR";
+
+// 用户模板覆盖目录，其下与内置模板层同名的目录中的文件优先于内置文件
+pub fn overlay_dir() -> Option<PathBuf> {
+    env::var_os(TEMPLATE_OVERLAY_ENV)
+        .filter(|dir| !dir.is_empty())
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|home| home.join(".elza").join("templates")))
+        .filter(|dir| dir.is_dir())
+}
 
-#[derive(Clone)]
-enum TemplateType {
-    WebpackReactJsDir,
-    WebpackReactTsDir,
-    ViteReactJsDir,
-    ViteReactTsDir,
-    RspackReactJsDir,
-    RspackReactTsDir,
-    FarmReactDir,
-    ElzaReactJsDir,
-    ElzaReactTsDir,
-    CommonDir,
-    CommonReactJsDir,
-    CommonReactTsDir,
+// 模板层，对应 templates/ 下的一个目录，外部模板则为其根目录下的目录
+#[derive(Clone, Debug)]
+struct TemplateType {
+    dir: String,
+    root: Option<PathBuf>,
+    // 内置模板层在覆盖目录中对应的目录
+    overlay: Option<PathBuf>,
 }
 
 impl TemplateType {
-    fn get_file_content(&self, filename: &str) -> Option<EmbeddedFile> {
-        match self {
-            TemplateType::WebpackReactJsDir => WebpackReactJsTemplate::get(filename),
-            TemplateType::WebpackReactTsDir => WebpackReactTsTemplate::get(filename),
-            TemplateType::ViteReactJsDir => ViteReactJsTemplate::get(filename),
-            TemplateType::ViteReactTsDir => ViteReactTsTemplate::get(filename),
-            TemplateType::RspackReactJsDir => RspackReactJsTemplate::get(filename),
-            TemplateType::RspackReactTsDir => RspackReactTsTemplate::get(filename),
-            TemplateType::FarmReactDir => FarmReactTemplate::get(filename),
-            TemplateType::ElzaReactJsDir => ElzaReactJsTemplate::get(filename),
-            TemplateType::ElzaReactTsDir => ElzaReactTsTemplate::get(filename),
-            TemplateType::CommonDir => Common::get(filename),
-            TemplateType::CommonReactJsDir => CommonReactJs::get(filename),
-            TemplateType::CommonReactTsDir => CommonReactTs::get(filename),
+    fn new(layer: &Layer, overlay_dir: Option<&Path>) -> Self {
+        let overlay = match &layer.root {
+            Some(_) => None,
+            None => overlay_dir
+                .map(|dir| dir.join(&layer.dir))
+                .filter(|dir| dir.is_dir()),
+        };
+        Self {
+            dir: layer.dir.clone(),
+            root: layer.root.clone(),
+            overlay,
         }
     }
-    fn iter_files(&self) -> Box<dyn Iterator<Item = std::borrow::Cow<'static, str>>> {
-        match self {
-            TemplateType::WebpackReactJsDir => Box::new(WebpackReactJsTemplate::iter()),
-            TemplateType::WebpackReactTsDir => Box::new(WebpackReactTsTemplate::iter()),
-            TemplateType::ViteReactJsDir => Box::new(ViteReactJsTemplate::iter()),
-            TemplateType::ViteReactTsDir => Box::new(ViteReactTsTemplate::iter()),
-            TemplateType::RspackReactJsDir => Box::new(RspackReactJsTemplate::iter()),
-            TemplateType::RspackReactTsDir => Box::new(RspackReactTsTemplate::iter()),
-            TemplateType::FarmReactDir => Box::new(FarmReactTemplate::iter()),
-            TemplateType::ElzaReactJsDir => Box::new(ElzaReactJsTemplate::iter()),
-            TemplateType::ElzaReactTsDir => Box::new(ElzaReactTsTemplate::iter()),
-            TemplateType::CommonDir => Box::new(Common::iter()),
-            TemplateType::CommonReactJsDir => Box::new(CommonReactJs::iter()),
-            TemplateType::CommonReactTsDir => Box::new(CommonReactTs::iter()),
+    fn get_file_content(&self, filename: &str) -> Option<Cow<'static, [u8]>> {
+        if let Some(data) = self
+            .overlay
+            .as_ref()
+            .and_then(|overlay| fs::read(overlay.join(filename)).ok())
+        {
+            return Some(Cow::Owned(data));
+        }
+        match &self.root {
+            Some(root) => fs::read(root.join(&self.dir).join(filename))
+                .ok()
+                .map(Cow::Owned),
+            None => Templates::get(&format!("{}/{}", self.dir, filename)).map(|file| file.data),
         }
     }
+    fn iter_files(&self) -> Result<Vec<String>> {
+        let Some(root) = &self.root else {
+            let prefix = format!("{}/", self.dir);
+            let mut files: BTreeSet<String> = Templates::iter()
+                .filter_map(|path| path.strip_prefix(&prefix).map(str::to_string))
+                .collect();
+            // 覆盖目录中新增的文件同样加入该层
+            if let Some(overlay) = &self.overlay {
+                files.extend(list_files(overlay)?);
+            }
+            return Ok(files.into_iter().collect());
+        };
+        // 外部模板的清单文件不属于项目文件
+        let dir = root.join(&self.dir);
+        let manifest = root.join(TEMPLATE_MANIFEST_FILE);
+        Ok(list_files(&dir)?
+            .into_iter()
+            .filter(|filename| dir.join(filename) != manifest)
+            .collect())
+    }
 }
 
-#[derive(Clone, Debug)]
-pub enum ProjectType {
-    WebpackReactJs,
-    WebpackReactTs,
-    ViteReactJs,
-    ViteReactTs,
-    RspackReactJs,
-    RspackReactTs,
-    FarmReact,
-    ElzaReactJs,
-    ElzaReactTs,
-}
-
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CopyType {
     Template,
     Common,
 }
 
-// 项目初始化
-pub fn start(project_name: &str, config: InlineConfig) -> Result<()> {
+// 项目初始化的附加选项
+#[derive(Clone, Debug, Default)]
+pub struct StartOptions {
+    // 为空时跳过依赖安装
+    pub install: Option<PackageManager>,
+    // 为空时使用预设版本，否则从该镜像源解析依赖的最新版本
+    pub registry: Option<String>,
+    // 通过 --from 指定的外部模板清单，为空时按配置查找内置模板
+    pub manifest: Option<TemplateManifest>,
+    // 仅预览将要生成的文件及 package.json，不写入磁盘
+    pub dry_run: bool,
+}
+
+// 项目初始化，返回依赖是否已安装
+pub async fn start(
+    project_name: &str,
+    config: InlineConfig,
+    options: StartOptions,
+) -> Result<bool> {
     // 初始化项目路径
     let project_dir = PathBuf::from(project_name);
-    create_project_dir(&project_dir)?;
-    // 获取项目类型
-    let (project_type, template_type) =
-        get_project_type(config.pack_tool, config.frame, config.lang);
-
-    // 复制特定模板文件
-    copy_template_files(
-        &project_dir,
-        template_type,
-        config.clone(),
-        CopyType::Template,
-    )?;
+    // 获取模板清单
+    let manifest = match options.manifest {
+        Some(manifest) => manifest,
+        None => TemplateManifest::find(config.frame, config.pack_tool, config.lang)?,
+    };
+    logger::info(&t!("build.template", name = manifest.name));
+    if let (None, Some(dir)) = (&manifest.root, overlay_dir()) {
+        logger::info(&t!("build.overlay", path = dir.display()));
+    }
+    let context = manifest.get_context(&config, project_name)?;
+    // 先完成渲染，避免模板出错时留下不完整的项目目录
+    let plan = plan_project(&manifest, config, &context)?;
+    // 预览模式下在内存中完成 package.json 的修改，不写入磁盘
+    if options.dry_run {
+        let mut pj = plan.package_json(&project_dir)?;
+        let dependencies = update_package_json(
+            &mut pj,
+            project_name,
+            &manifest,
+            config,
+            options.registry.as_deref(),
+        )
+        .await?;
+        print_plan(&project_dir, &plan, &pj, &dependencies);
+        return Ok(false);
+    }
+    let files = plan.into_files();
+    // 先生成到临时目录，全部完成后再移动到项目目录，失败或中断时不留下残缺的目录
+    logger::event(&t!("build.create_dir"));
+    let transaction = ProjectTransaction::begin(&project_dir)?;
+    let work_dir = transaction.dir().to_path_buf();
+
+    // 写入模板层文件
+    for (target, data) in &files {
+        write_project_file(&work_dir, target, data)?;
+    }
 
-    // 复制公共文件
-    copy_common_files(&project_dir, config, CopyType::Common)?;
+    logger::info(&t!("build.files_created"));
+    let mut pj = PackageJson::new(&work_dir)?;
+    let dependencies = update_package_json(
+        &mut pj,
+        project_name,
+        &manifest,
+        config,
+        options.registry.as_deref(),
+    )
+    .await?;
+    // 写入
+    pj.write()?;
+    logger::info(&t!("build.dependencies_added"));
+    // 记录生成信息，package.json 以写入依赖后的内容为准
+    let mut rc = ElzaRc::new(config, &manifest, &files, &dependencies)?;
+    rc.record_file(&work_dir, "package.json")?;
+    rc.write(&work_dir)?;
+    write_base(&work_dir, &files)?;
+    git_init(&work_dir)?;
+    transaction.commit()?;
+    logger::info(&t!("build.dir_created"));
+    // 依赖安装失败不影响项目创建，提示用户手动安装
+    let installed = match options.install {
+        Some(pm) => match pm.install(&project_dir) {
+            std::result::Result::Ok(()) => true,
+            Err(e) => {
+                logger::warning(&t!("build.install_failed", error = e));
+                false
+            }
+        },
+        None => false,
+    };
+    for tip in &manifest.tips {
+        logger::full_info(&tip.message());
+    }
+    Ok(installed)
+}
 
-    logger::info("文件创建完成");
-    match config.pack_tool {
-        PackTool::Webpack => {
-            update_webpack_rules(&project_dir, config)?;
-        }
-        PackTool::Rsbuild => {
-            update_rsbuild_config(&project_dir, config)?;
-        }
-        PackTool::Vite => {}
-        PackTool::Farm => {
-            update_farm_config(&project_dir, config)?;
-        }
-        PackTool::Elza => {}
+// 写入项目名称、模板清单中的字段及预设依赖，返回添加的依赖
+async fn update_package_json(
+    pj: &mut PackageJson,
+    project_name: &str,
+    manifest: &TemplateManifest,
+    config: InlineConfig,
+    registry: Option<&str>,
+) -> Result<Vec<Dependency>> {
+    let mut dependencies = manifest.get_dependencies(&config)?;
+    if let Some(registry) = registry {
+        dependencies = resolve_latest_versions(dependencies, registry).await;
     }
-    let mut pj = PackageJson::new(&project_dir)?;
+    apply_package_json(pj, project_name, manifest, &dependencies)?;
+    Ok(dependencies)
+}
+
+pub fn apply_package_json(
+    pj: &mut PackageJson,
+    project_name: &str,
+    manifest: &TemplateManifest,
+    dependencies: &[Dependency],
+) -> Result<()> {
     // 更新package.json基本信息
     pj.update_basic(PackageBasicInfo {
         name: project_name.to_string(),
-        project_type,
+        fields: manifest.package.clone(),
     })?;
     // 更新package.json依赖项
-    let deps = vec![
-        config.frame.get_dependencies(),
-        config.pack_tool.get_dependencies(),
-        config.lang.get_dependencies(config.pack_tool, config.frame),
-        config.loader.get_dependencies(),
-        // config.state.get_dependencies(),
-        // config.ui.get_dependencies(),
-        config.css.get_dependencies(config.pack_tool),
-    ];
-    let flatten_deps: Vec<Dependency> = deps.into_iter().flatten().collect();
-    for dep in flatten_deps {
+    for dep in dependencies {
         pj.update_dependencies(&dep.name, &dep.version, dep.mod_type)?;
     }
     // 对依赖项排序
     pj.sort();
-    // 写入
-    pj.write()?;
-    logger::info("预设依赖项添加完成");
-    git_init(&project_dir)?;
-    match config.pack_tool {
-        PackTool::Webpack | PackTool::Rsbuild => {
-            logger::full_info(
-                &format!(
-                    "[你知道吗？] {:?}模板内置约定式路由插件，依赖安装完成启动项目即可生成路由文件，详见 https://github.com/865713897/webpack-plugin-auto-routes#readme",
-                    config.pack_tool
-                )
-            );
-        }
-        PackTool::Farm => {
-            logger::full_info(
-                &format!(
-                    "[你知道吗？] {:?}模板内置约定式路由插件，依赖安装完成启动项目即可生成路由文件，详见 https://github.com/865713897/farm-plugin-auto-routes#readme",
-                    config.pack_tool
-                )
-            );
-        }
-        _ => {}
-    }
     Ok(())
 }
 
-// 获取项目类型
-fn get_project_type(
-    pack_tool: PackTool,
-    frame: FrameWork,
-    lang: CodeLanguage,
-) -> (ProjectType, TemplateType) {
-    match (pack_tool, frame, lang) {
-        (PackTool::Webpack, FrameWork::React, CodeLanguage::Js) => {
-            (ProjectType::WebpackReactJs, TemplateType::WebpackReactJsDir)
-        }
-        (PackTool::Webpack, FrameWork::React, CodeLanguage::Ts) => {
-            (ProjectType::WebpackReactTs, TemplateType::WebpackReactTsDir)
-        }
-        (PackTool::Vite, FrameWork::React, CodeLanguage::Js) => {
-            (ProjectType::ViteReactJs, TemplateType::ViteReactJsDir)
-        }
-        (PackTool::Vite, FrameWork::React, CodeLanguage::Ts) => {
-            (ProjectType::ViteReactTs, TemplateType::ViteReactTsDir)
-        }
-        (PackTool::Rsbuild, FrameWork::React, CodeLanguage::Js) => {
-            (ProjectType::RspackReactJs, TemplateType::RspackReactJsDir)
-        }
-        (PackTool::Rsbuild, FrameWork::React, CodeLanguage::Ts) => {
-            (ProjectType::RspackReactTs, TemplateType::RspackReactTsDir)
-        }
-        (PackTool::Farm, FrameWork::React, _) => {
-            (ProjectType::FarmReact, TemplateType::FarmReactDir)
-        }
-        (PackTool::Elza, FrameWork::React, CodeLanguage::Js) => {
-            (ProjectType::ElzaReactJs, TemplateType::ElzaReactJsDir)
-        }
-        (PackTool::Elza, FrameWork::React, CodeLanguage::Ts) => {
-            (ProjectType::ElzaReactTs, TemplateType::ElzaReactTsDir)
-        }
-    }
+// 模板渲染结果，记录每个文件所在的模板层
+pub struct ProjectPlan {
+    // 键为项目内的相对路径，值为模板层目录及渲染后的内容
+    pub files: BTreeMap<String, (String, Vec<u8>)>,
+    // 被模板清单规则跳过的文件及其所在的模板层目录
+    pub skipped: Vec<(String, String)>,
 }
 
-// 创建项目目录
-fn create_project_dir(project_dir: &PathBuf) -> Result<()> {
-    logger::event("开始创建项目目录");
-    handle_result(fs::create_dir(project_dir), "创建项目目录失败");
-    logger::info("创建项目目录成功");
-    Ok(())
-}
+impl ProjectPlan {
+    // 渲染结果中的 package.json，尚未写入项目名称及依赖
+    pub fn package_json(&self, project_dir: &Path) -> Result<PackageJson> {
+        let content = self
+            .files
+            .get("package.json")
+            .context(ElzaError::Template(t!("build.missing_package_json")))?;
+        PackageJson::parse(project_dir, std::str::from_utf8(&content.1)?)
+    }
 
-// 复制公共模块代码
-fn copy_common_files(
-    project_dir: &PathBuf,
-    config: InlineConfig,
-    copy_type: CopyType,
-) -> Result<()> {
-    // 复制通用文件
-    copy_template_files(
-        project_dir,
-        TemplateType::CommonDir,
-        config,
-        copy_type.clone(),
-    )?;
-    // 根据不同模板类型复制不同文件
-    let common_react_dir = match config.lang {
-        CodeLanguage::Js => TemplateType::CommonReactJsDir,
-        CodeLanguage::Ts => TemplateType::CommonReactTsDir,
-    };
-    copy_template_files(project_dir, common_react_dir, config, copy_type.clone())?;
-    Ok(())
+    pub fn into_files(self) -> BTreeMap<String, Vec<u8>> {
+        self.files
+            .into_iter()
+            .map(|(target, (_, data))| (target, data))
+            .collect()
+    }
 }
 
-// 复制模板文件
-fn copy_template_files(
-    project_dir: &PathBuf,
-    template_type: TemplateType,
+// 渲染当前配置下的全部项目文件，键为项目内的相对路径，后面的模板层会覆盖前面的同名文件
+pub fn render_project(
+    manifest: &TemplateManifest,
     config: InlineConfig,
-    copy_type: CopyType,
-) -> Result<()> {
-    for filename in template_type.iter_files() {
-        if should_skip_file(&filename, config, copy_type.clone()) {
-            continue;
-        }
-        copy_template_file(project_dir, filename.as_ref(), template_type.clone())?;
-    }
-    Ok(())
+    context: &TemplateContext,
+) -> Result<BTreeMap<String, Vec<u8>>> {
+    Ok(plan_project(manifest, config, context)?.into_files())
 }
 
-// 判断是否应该忽略某个文件
-fn should_skip_file(filename: &str, config: InlineConfig, copy_type: CopyType) -> bool {
-    match config.pack_tool {
-        PackTool::Webpack => {
-            (config.loader == JsLoader::Babel && filename.contains(".swcrc"))
-                || (config.loader == JsLoader::Swc && filename.contains("babel.config.json"))
-                || (copy_type == CopyType::Common && filename.contains("src/index"))
-        }
-        // PackTool::Rsbuild => copy_type == CopyType::Common && filename.contains("src/index"),
-        PackTool::Farm => {
-            copy_type == CopyType::Common
-                && (filename.contains("public/index.html")
-                    || filename.contains("src/router")
-                    || filename.contains("typings"))
-        }
-        PackTool::Vite => {
-            copy_type == CopyType::Common
-                && (filename.contains("public/index.html")
-                    || filename.contains("src/router")
-                    || filename.contains("typings"))
+pub fn plan_project(
+    manifest: &TemplateManifest,
+    config: InlineConfig,
+    context: &TemplateContext,
+) -> Result<ProjectPlan> {
+    let mut plan = ProjectPlan {
+        files: BTreeMap::new(),
+        skipped: vec![],
+    };
+    let overlay_dir = overlay_dir();
+    for layer in &manifest.layers {
+        let template_type = TemplateType::new(layer, overlay_dir.as_deref());
+        for filename in template_type.iter_files()? {
+            // 文件名同样支持模板语法，渲染为空时不生成该文件
+            let target = render(&filename, context).with_context(|| {
+                ElzaError::Template(t!("template.render_filename_failed", path = filename))
+            })?;
+            if target.is_empty() {
+                continue;
+            }
+            if manifest.should_skip_file(&target, layer.copy_type, &config)? {
+                plan.skipped.push((target, layer.dir.clone()));
+                continue;
+            }
+            let data = render_template_file(&template_type, &filename, context)?;
+            plan.files.insert(target, (layer.dir.clone(), data));
         }
-        _ => false,
     }
+    Ok(plan)
 }
 
-// 遍历template内部文件，并写入
-fn copy_template_file(
-    project_dir: &PathBuf,
+// 文本文件经过模板渲染，其余文件原样返回
+fn render_template_file(
+    template_type: &TemplateType,
     filename: &str,
-    template_type: TemplateType,
-) -> Result<()> {
-    let file_content = handle_option(
-        template_type.get_file_content(filename),
-        &format!("获取模板文件内容失败: {}", filename),
-    );
+    context: &TemplateContext,
+) -> Result<Vec<u8>> {
+    let file_content = template_type
+        .get_file_content(filename)
+        .with_context(|| ElzaError::Template(t!("template.read_failed", path = filename)))?;
+    let data = match std::str::from_utf8(&file_content) {
+        std::result::Result::Ok(text) => render(text, context)
+            .with_context(|| ElzaError::Template(t!("template.render_failed", path = filename)))?
+            .into_bytes(),
+        Err(_) => file_content.into_owned(),
+    };
+    Ok(data)
+}
 
-    let file_path = project_dir.join(filename);
-    let directory_path = handle_option(file_path.parent(), "获取文件夹路径失败");
+// 写入项目文件
+pub fn write_project_file(project_dir: &Path, target: &str, data: &[u8]) -> Result<()> {
+    let file_path = project_dir.join(target);
+    let directory_path = file_path.parent().with_context(|| {
+        ElzaError::Io(t!("file.parent_failed", path = format!("{:?}", file_path)))
+    })?;
 
-    logger::event(&format!("开始创建文件: {}", filename));
-    handle_result(
-        fs::create_dir_all(directory_path),
-        &format!("创建目录失败: {:?}", directory_path),
-    );
-    handle_result(
-        fs::write(&file_path, file_content.data),
-        &format!("写入文件失败: {:?}", file_path),
+    logger::record(
+        "file_created",
+        &t!("file.create", path = target),
+        json!({ "path": target }),
     );
+    fs::create_dir_all(directory_path).with_context(|| {
+        ElzaError::Io(t!(
+            "file.create_dir_failed",
+            path = format!("{:?}", directory_path)
+        ))
+    })?;
+    fs::write(&file_path, data).with_context(|| {
+        ElzaError::Io(t!(
+            "file.write_file_failed",
+            path = format!("{:?}", file_path)
+        ))
+    })?;
     Ok(())
 }
 
 // 初始化git仓库
-fn git_init(project_dir: &PathBuf) -> Result<()> {
-    logger::event("git 初始化");
-    run_git_command(project_dir, &["init"], "git 初始化失败")?;
-    logger::info("git 初始化完成");
+fn git_init(project_dir: &Path) -> Result<()> {
+    logger::event(&t!("git.init"));
+    run_git_command(project_dir, &["init"], &t!("git.init_failed"))?;
+    logger::record_info("git_initialized", &t!("git.init_done"), Value::Null);
     Ok(())
 }
 
 // 执行git命令
-fn run_git_command(project_dir: &PathBuf, args: &[&str], error_msg: &str) -> Result<()> {
+fn run_git_command(project_dir: &Path, args: &[&str], error_msg: &str) -> Result<()> {
+    logger::debug(&t!(
+        "common.run_command",
+        command = format!("git {}", args.join(" "))
+    ));
     let status = Command::new("git")
         .current_dir(project_dir)
         .args(args)
         .stdout(Stdio::null())
         .status()
-        .expect(error_msg);
+        .with_context(|| ElzaError::Git(t!("git.not_installed", message = error_msg)))?;
     if !status.success() {
-        logger::error(error_msg);
-        exit(1);
-    }
-    Ok(())
-}
-
-// 更新webpack rules
-fn update_webpack_rules(project_dir: &PathBuf, config: InlineConfig) -> Result<()> {
-    let file_name = match config.lang {
-        CodeLanguage::Js => "scripts/webpack.common.js",
-        CodeLanguage::Ts => "scripts/webpack.common.ts",
-    };
-    let replace_vec = match (config.css, config.loader) {
-        (CssPreset::Sass, JsLoader::Babel) => vec!["sass-loader", "scss", "babel-loader"],
-        (CssPreset::Sass, JsLoader::Swc) => vec!["sass-loader", "scss", "swc-loader"],
-        (CssPreset::Less, JsLoader::Babel) => vec!["less-loader", "less", "babel-loader"],
-        (CssPreset::Less, JsLoader::Swc) => vec!["less-loader", "less", "swc-loader"],
-        _ => vec![],
-    };
-    update_config_file(project_dir, file_name, replace_vec)
-}
-
-// 更新rsbuild.config.js
-fn update_rsbuild_config(project_dir: &PathBuf, config: InlineConfig) -> Result<()> {
-    let file_name = match config.lang {
-        CodeLanguage::Js => "rsbuild.config.mjs",
-        CodeLanguage::Ts => "rsbuild.config.ts",
-    };
-    let replace_vec = match config.css {
-        CssPreset::Sass => vec![
-            "\nimport { pluginSass } from '@rsbuild/plugin-sass';",
-            "pluginSass()",
-        ],
-        CssPreset::Less => vec![
-            "\nimport { pluginLess } from '@rsbuild/plugin-less';",
-            "pluginLess()",
-        ],
-        _ => vec![],
-    };
-    update_config_file(project_dir, file_name, replace_vec)
-}
-
-// 更新farm.config.ts
-fn update_farm_config(project_dir: &PathBuf, config: InlineConfig) -> Result<()> {
-    let replace_vec = match config.css {
-        CssPreset::Sass => vec!["", "'@farmfe/plugin-sass'"],
-        CssPreset::Less => vec!["\nimport less from '@farmfe/js-plugin-less';", "less()"],
-        _ => vec![],
-    };
-    update_config_file(project_dir, "farm.config.ts", replace_vec)
-}
-
-// 通用更新配置文件
-fn update_config_file(
-    project_dir: &PathBuf,
-    file_name: &str,
-    replace_vec: Vec<&str>,
-) -> Result<()> {
-    let mut path = project_dir.clone();
-    path.push(file_name);
-    let mut content = fs::read_to_string(&path).unwrap();
-    for (i, item) in replace_vec.iter().enumerate() {
-        content = content.replace(&format!("`placeholder:{i}`"), item);
+        bail!(ElzaError::Git(error_msg.to_string()));
     }
-    handle_result(fs::write(&path, content), &format!("更新 {:?} 失败", path));
     Ok(())
 }
@@ -1,42 +1,81 @@
-use anyhow::{ Ok as AnyhowOk, Result };
+use anyhow::{ bail, Ok as AnyhowOk, Result };
 use clap::ValueEnum;
+use serde::{ Deserialize, Serialize };
+use serde_json::json;
 use console::style;
-use std::{ fmt, path::PathBuf, process::exit };
+use std::{ fmt, path::PathBuf };
 use tokio::{ join, spawn };
 
 use super::build;
 use super::pack;
-use super::select::create_list;
-use crate::utils::{ logger, utils };
+use super::package_manager::PackageManager;
+use super::select::{ create_list, ensure_interactive };
+use super::source::ExternalTemplate;
+use crate::utils::{ error::ElzaError, logger, utils };
 
-#[derive(Copy, Clone, Debug)]
-pub enum DependenciesMod {
-    Dev,
-    Prod,
+// create 命令的可选参数，未指定的选项会在交互中询问
+#[derive(Clone, Debug, Default)]
+pub struct CreateOptions {
+    pub template: Option<pack::PackTool>,
+    pub frame_work: Option<FrameWork>,
+    pub lang: Option<CodeLanguage>,
+    pub loader: Option<JsLoader>,
+    pub css: Option<CssPreset>,
+    pub utility: Option<UtilityCss>,
+    pub state: Option<StateManagement>,
+    pub ui: Option<UIDesign>,
+    pub pm: Option<PackageManager>,
+    // 创建完成后是否安装依赖
+    pub install: bool,
+    // 是否从镜像源解析依赖的最新版本
+    pub latest: bool,
+    // 指定镜像源，默认读取 ~/.npmrc
+    pub registry: Option<String>,
+    // 外部模板来源：本地目录、git 仓库或 npm 包
+    pub from: Option<String>,
+    // 仅预览将要生成的内容，不写入磁盘
+    pub dry_run: bool,
+    // 未指定的选项均使用默认值
+    pub yes: bool,
 }
 
-#[derive(Copy, Clone, Debug)]
-pub struct Dependency {
-    pub name: &'static str,
-    pub version: &'static str,
-    pub mod_type: DependenciesMod,
-}
-
-pub async fn create_project(
-    project_name: String,
-    template: Option<pack::PackTool>,
-    frame_work: Option<FrameWork>
-) -> Result<()> {
+pub async fn create_project(project_name: String, mut options: CreateOptions) -> Result<()> {
     let current_version = env!("CARGO_PKG_VERSION");
     logger::info(
         &format!("{}{}", style("elza-cli v").green().bold(), style(current_version).green().bold())
     );
     // 如果这个目录已经存在
     if PathBuf::from(&project_name).exists() {
-        logger::error(&format!("创建失败: {:#?} 已经存在！", &project_name));
-        return AnyhowOk(());
+        bail!(ElzaError::Io(t!("create.exists", path = format!("{:#?}", &project_name))));
+    }
+    logger::info(&t!("create.preset"));
+    // 外部模板需在选择配置前获取，清单中声明的框架、打包工具及语言作为默认值
+    let external = match &options.from {
+        Some(spec) => Some(ExternalTemplate::fetch(spec)?),
+        None => None,
+    };
+    let manifest = match &external {
+        Some(external) => Some(external.manifest()?),
+        None => None,
+    };
+    if let Some(manifest) = &manifest {
+        options.frame_work = options.frame_work.or(manifest.frame);
+        options.template = options.template.or(manifest.pack_tool);
+        if let [lang] = manifest.lang[..] {
+            options.lang = options.lang.or(Some(lang));
+        }
     }
-    logger::info("开始预设项目...");
+    let pm = PackageManager::detect(options.pm);
+    let dry_run = options.dry_run;
+    let project_dir = project_name.clone();
+    let start_options = build::StartOptions {
+        install: options.install.then_some(pm),
+        registry: options.latest.then(|| {
+            options.registry.clone().unwrap_or_else(utils::get_user_npm_registry)
+        }),
+        manifest,
+        dry_run: options.dry_run,
+    };
 
     // 获取最新版本
     let latest_version_future = spawn(async move {
@@ -44,410 +83,412 @@ pub async fn create_project(
     });
 
     let config_future = spawn(async move {
-        let frame = frame_selector(frame_work)?;
-        let pack_tool = pack::pack_tool_selector(template)?;
-        let lang = match pack_tool {
-            pack::PackTool::Farm => CodeLanguage::Ts,
-            _ => lang_selector()?,
-        };
-        let loader = match pack_tool {
-            pack::PackTool::Webpack => js_loader_selector()?,
-            _ => JsLoader::None,
-        };
-        let css = match pack_tool {
-            pack::PackTool::Elza => CssPreset::None,
-            _ => css_selector()?,
-        };
-        build
-            ::start(project_name.as_str(), build::InlineConfig {
-                frame,
-                pack_tool,
-                lang,
-                loader,
-                css,
-            })
-            .map_err(|e| anyhow::anyhow!(e))
+        let config = select_config(&options)?;
+        let installed = build::start(project_name.as_str(), config, start_options).await?;
+        AnyhowOk((config, installed))
     });
 
-    let (_, latest_version_result) = join!(config_future, latest_version_future);
+    let (config_result, latest_version_result) = join!(config_future, latest_version_future);
     let latest_version = match latest_version_result {
         Ok(inner_result) =>
             match inner_result {
                 Ok(version) => version, // 内层 Result 是 Ok，获取 version
                 Err(e) => {
-                    logger::warning(&format!("获取最新版本失败: {}", e));
+                    logger::warning(&t!("version.check_failed", error = e));
                     "".to_string() // 内层 Result 是 Err，返回默认值
                 }
             }
         Err(_) => {
-            logger::warning(&format!("获取最新版本失败"));
+            logger::warning(&t!("version.check_failed_plain"));
             "".to_string() // 如果任务本身失败，返回默认值
         }
     };
     utils::compare_versions(current_version, &latest_version);
-    logger::ready("项目初始化完成");
+    let (config, installed) = config_result??;
+    if dry_run {
+        logger::ready(&t!("create.preview_done"));
+        return AnyhowOk(());
+    }
+    logger::summary(
+        &t!("create.done"),
+        json!({
+            "path": std::env::current_dir()?.join(&project_dir),
+            "config": config,
+            "packageManager": pm.to_string(),
+            "installed": installed,
+        })
+    );
+    logger::full_info(&format!("cd {}", project_dir));
+    if !installed {
+        logger::full_info(&pm.install_command());
+    }
+    logger::full_info(&pm.run_command("start"));
     AnyhowOk(())
 }
 
+// 根据参数确定生成配置，未指定的选项在交互中询问，yes 时使用默认值
+pub fn select_config(options: &CreateOptions) -> Result<build::InlineConfig> {
+    let yes = options.yes;
+    let frame = frame_selector(options.frame_work, yes)?;
+    let pack_tool = pack::pack_tool_selector(options.template, yes)?;
+    let lang = match lang_options(pack_tool) {
+        [lang] => {
+            if options.lang.is_some_and(|value| value != *lang) {
+                logger::warning(&t!("select.farm_ts_only"));
+            }
+            *lang
+        }
+        _ => lang_selector(options.lang, yes)?,
+    };
+    let loader = match loader_options(pack_tool) {
+        [loader] => {
+            if options.loader.is_some() {
+                logger::warning(&t!("select.loader_ignored", tool = pack_tool));
+            }
+            *loader
+        }
+        _ => js_loader_selector(options.loader, yes)?,
+    };
+    let css = match css_options(pack_tool, frame)[..] {
+        [css] => {
+            if options.css.is_some() {
+                logger::warning(&t!("select.css_ignored"));
+            }
+            css
+        }
+        _ => css_selector(options.css, pack_tool, frame, yes)?,
+    };
+    let utility = match utility_options(pack_tool) {
+        [utility] => {
+            if options.utility.is_some() {
+                logger::warning(&t!("select.utility_ignored"));
+            }
+            *utility
+        }
+        _ => utility_css_selector(options.utility, yes)?,
+    };
+    let state = match state_options(frame) {
+        [state] => {
+            if options.state.is_some() {
+                logger::warning(&t!("select.state_ignored", frame = frame));
+            }
+            *state
+        }
+        _ => state_selector(options.state, yes)?,
+    };
+    let ui = match ui_options(frame) {
+        [ui] => {
+            if options.ui.is_some() {
+                logger::warning(&t!("select.ui_ignored", frame = frame));
+            }
+            *ui
+        }
+        _ => ui_selector(options.ui, yes)?,
+    };
+    AnyhowOk(build::InlineConfig {
+        frame,
+        pack_tool,
+        lang,
+        loader,
+        css,
+        utility,
+        state,
+        ui,
+    })
+}
+
+// 各选项在给定框架及打包工具下的可选值，只有一个可选值时无需询问
+// farm 模板仅支持 typescript
+pub fn lang_options(pack_tool: pack::PackTool) -> &'static [CodeLanguage] {
+    match pack_tool {
+        pack::PackTool::Farm => &[CodeLanguage::Ts],
+        _ => &[CodeLanguage::Ts, CodeLanguage::Js],
+    }
+}
+
+// 仅 webpack 模板需要选择 loader
+pub fn loader_options(pack_tool: pack::PackTool) -> &'static [JsLoader] {
+    match pack_tool {
+        pack::PackTool::Webpack => &[JsLoader::Babel, JsLoader::Swc],
+        _ => &[JsLoader::None],
+    }
+}
+
+// elza 模板无需选择样式方案
+pub fn css_options(pack_tool: pack::PackTool, frame: FrameWork) -> Vec<CssPreset> {
+    match pack_tool {
+        pack::PackTool::Elza => vec![CssPreset::None],
+        _ =>
+            CssPreset::value_variants()
+                .iter()
+                .copied()
+                .filter(|css| css.supported_by(frame))
+                .collect(),
+    }
+}
+
+// elza 模板暂不支持原子化 CSS
+pub fn utility_options(pack_tool: pack::PackTool) -> &'static [UtilityCss] {
+    match pack_tool {
+        pack::PackTool::Elza => &[UtilityCss::None],
+        _ => &[UtilityCss::None, UtilityCss::Tailwind, UtilityCss::Unocss],
+    }
+}
+
+// 状态管理预设仅支持 React
+pub fn state_options(frame: FrameWork) -> &'static [StateManagement] {
+    match frame {
+        FrameWork::React =>
+            &[
+                StateManagement::None,
+                StateManagement::Redux,
+                StateManagement::Zustand,
+                StateManagement::Mobx,
+                StateManagement::Jotai,
+            ],
+        _ => &[StateManagement::None],
+    }
+}
+
+// 组件库预设仅支持 React
+pub fn ui_options(frame: FrameWork) -> &'static [UIDesign] {
+    match frame {
+        FrameWork::React => &[UIDesign::None, UIDesign::Antd, UIDesign::Arco, UIDesign::Mui],
+        _ => &[UIDesign::None],
+    }
+}
+
 // 框架选择
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum FrameWork {
     React,
+    Vue,
 }
 
 impl fmt::Display for FrameWork {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             FrameWork::React => write!(f, "React"),
+            FrameWork::Vue => write!(f, "Vue"),
         }
     }
 }
 
-impl FrameWork {
-    pub fn get_dependencies(&self) -> Vec<Dependency> {
-        match self {
-            FrameWork::React =>
-                vec![
-                    Dependency {
-                        name: "axios",
-                        version: "^1.7.9",
-                        mod_type: DependenciesMod::Prod,
-                    },
-                    Dependency {
-                        name: "react",
-                        version: "^18.2.0",
-                        mod_type: DependenciesMod::Prod,
-                    },
-                    Dependency {
-                        name: "react-dom",
-                        version: "^18.2.0",
-                        mod_type: DependenciesMod::Prod,
-                    },
-                    Dependency {
-                        name: "react-router-dom",
-                        version: "^6.23.1",
-                        mod_type: DependenciesMod::Prod,
-                    }
-                ],
-        }
-    }
-}
-
-fn frame_selector(frame_work: Option<FrameWork>) -> Result<FrameWork> {
+fn frame_selector(frame_work: Option<FrameWork>, yes: bool) -> Result<FrameWork> {
     match frame_work {
         Some(frame) => AnyhowOk(frame),
+        None if yes => AnyhowOk(FrameWork::React),
         None => {
-            logger::pick("请选择项目框架");
+            ensure_interactive("--frame")?;
+            logger::pick(&t!("select.frame"));
             let items = vec!["react", "vue"];
             let selection = create_list(&items, 0)?;
             match selection {
                 0 => AnyhowOk(FrameWork::React),
-                _ => {
-                    logger::error(&format!("暂不支持: {}", &items[selection]));
-                    exit(1);
-                }
+                1 => AnyhowOk(FrameWork::Vue),
+                _ => bail!(ElzaError::InvalidCombination(t!("select.unsupported", item = items[selection]))),
             }
         }
     }
 }
 
 // 语言选择
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CodeLanguage {
     Js,
     Ts,
 }
 
-impl CodeLanguage {
-    pub fn get_dependencies(
-        &self,
-        build_tool: pack::PackTool,
-        frame: FrameWork
-    ) -> Vec<Dependency> {
-        let common = vec![
-            Dependency {
-                name: "@types/react",
-                version: "^18.3.2",
-                mod_type: DependenciesMod::Dev,
-            },
-            Dependency {
-                name: "@types/react-dom",
-                version: "^18.3.0",
-                mod_type: DependenciesMod::Dev,
+fn lang_selector(lang: Option<CodeLanguage>, yes: bool) -> Result<CodeLanguage> {
+    match lang {
+        Some(lang) => AnyhowOk(lang),
+        None if yes => AnyhowOk(CodeLanguage::Ts),
+        None => {
+            ensure_interactive("--lang")?;
+            logger::pick(&t!("select.lang"));
+            let items = vec!["typescript", "javascript"];
+            let selection = create_list(&items, 0)?;
+            match selection {
+                0 => AnyhowOk(CodeLanguage::Ts),
+                1 => AnyhowOk(CodeLanguage::Js),
+                _ => bail!(ElzaError::InvalidCombination(t!("select.unsupported", item = items[selection]))),
             }
-        ];
-        let mut dependencies = match (self, build_tool, frame) {
-            (CodeLanguage::Js, pack::PackTool::Webpack, FrameWork::React) => vec![],
-            (CodeLanguage::Ts, pack::PackTool::Webpack, FrameWork::React) =>
-                vec![
-                    Dependency {
-                        name: "@babel/preset-typescript",
-                        version: "^7.24.1",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "@types/node",
-                        version: "^20.12.12",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "@types/webpack",
-                        version: "^5.28.5",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "ts-node",
-                        version: "^10.9.2",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "typescript",
-                        version: "^5.5.2",
-                        mod_type: DependenciesMod::Dev,
-                    }
-                ],
-            (CodeLanguage::Js, pack::PackTool::Vite, FrameWork::React) =>
-                vec![Dependency {
-                    name: "@vitejs/plugin-react-swc",
-                    version: "^3.5.0",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (CodeLanguage::Ts, pack::PackTool::Vite, FrameWork::React) =>
-                vec![
-                    Dependency {
-                        name: "@vitejs/plugin-react-swc",
-                        version: "^3.5.0",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "typescript",
-                        version: "^5.5.2",
-                        mod_type: DependenciesMod::Dev,
-                    }
-                ],
-            (CodeLanguage::Js, pack::PackTool::Rsbuild, FrameWork::React) =>
-                vec![Dependency {
-                    name: "@rsbuild/plugin-react",
-                    version: "^1.0.7",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (CodeLanguage::Ts, pack::PackTool::Rsbuild, FrameWork::React) =>
-                vec![
-                    Dependency {
-                        name: "@rsbuild/plugin-react",
-                        version: "^1.0.7",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "typescript",
-                        version: "^5.5.2",
-                        mod_type: DependenciesMod::Dev,
-                    }
-                ],
-            (_, pack::PackTool::Farm, FrameWork::React) =>
-                vec![Dependency {
-                    name: "@farmfe/plugin-react",
-                    version: "^1.2.0",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (_, pack::PackTool::Elza, FrameWork::React) => vec![],
-        };
-
-        if self == &CodeLanguage::Ts {
-            dependencies.extend(common);
-        }
-        dependencies
-    }
-}
-
-fn lang_selector() -> Result<CodeLanguage> {
-    logger::pick("请选择语言");
-    let items = vec!["typescript", "javascript"];
-    let selection = create_list(&items, 0)?;
-    match selection {
-        0 => AnyhowOk(CodeLanguage::Ts),
-        1 => AnyhowOk(CodeLanguage::Js),
-        _ => {
-            logger::error("暂不支持");
-            exit(1);
         }
     }
 }
 
 // loader
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum JsLoader {
     Babel,
     Swc,
+    #[value(skip)]
     None,
 }
 
-impl JsLoader {
-    pub fn get_dependencies(&self) -> Vec<Dependency> {
-        match self {
-            JsLoader::Babel =>
-                vec![
-                    Dependency {
-                        name: "@babel/core",
-                        version: "^7.24.5",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "@babel/plugin-transform-runtime",
-                        version: "^7.24.7",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "@babel/preset-env",
-                        version: "^7.24.5",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "@babel/preset-react",
-                        version: "^7.24.1",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "@babel/runtime",
-                        version: "^7.24.7",
-                        mod_type: DependenciesMod::Prod,
-                    },
-                    Dependency {
-                        name: "babel-loader",
-                        version: "^9.1.3",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "babel-plugin-auto-css-module",
-                        version: "1.0.0",
-                        mod_type: DependenciesMod::Dev,
-                    }
-                ],
-            JsLoader::Swc =>
-                vec![
-                    Dependency {
-                        name: "@swc/core",
-                        version: "1.6.6",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "swc-loader",
-                        version: "0.2.6",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "swc-plugin-auto-css-module",
-                        version: "0.0.9",
-                        mod_type: DependenciesMod::Dev,
-                    }
-                ],
-            JsLoader::None => vec![],
-        }
-    }
-}
-
-fn js_loader_selector() -> Result<JsLoader> {
-    logger::pick("请选择loader");
-    let items = vec!["babel-loader", "swc-loader"];
-    let selection = create_list(&items, 0)?;
-    match selection {
-        0 => AnyhowOk(JsLoader::Babel),
-        1 => AnyhowOk(JsLoader::Swc),
-        _ => {
-            logger::error(&format!("暂不支持{}", &items[selection]));
-            exit(1);
+fn js_loader_selector(loader: Option<JsLoader>, yes: bool) -> Result<JsLoader> {
+    match loader {
+        Some(loader) => AnyhowOk(loader),
+        None if yes => AnyhowOk(JsLoader::Babel),
+        None => {
+            ensure_interactive("--loader")?;
+            logger::pick(&t!("select.loader"));
+            let items = vec!["babel-loader", "swc-loader"];
+            let selection = create_list(&items, 0)?;
+            match selection {
+                0 => AnyhowOk(JsLoader::Babel),
+                1 => AnyhowOk(JsLoader::Swc),
+                _ => bail!(ElzaError::InvalidCombination(t!("select.unsupported", item = items[selection]))),
+            }
         }
     }
 }
 
 // css预处理
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum CssPreset {
     Sass,
     Less,
+    #[serde(rename = "styled-components")]
+    StyledComponents,
+    Emotion,
     None,
 }
 
+impl fmt::Display for CssPreset {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CssPreset::Sass => write!(f, "sass"),
+            CssPreset::Less => write!(f, "less"),
+            CssPreset::StyledComponents => write!(f, "styled-components"),
+            CssPreset::Emotion => write!(f, "emotion"),
+            CssPreset::None => write!(f, "none"),
+        }
+    }
+}
+
 impl CssPreset {
-    pub fn get_dependencies(&self, build_tool: pack::PackTool) -> Vec<Dependency> {
-        match (self, build_tool) {
-            (CssPreset::Sass, pack::PackTool::Webpack) =>
-                vec![
-                    Dependency {
-                        name: "sass",
-                        version: "^1.77.6",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "sass-loader",
-                        version: "^14.2.1",
-                        mod_type: DependenciesMod::Dev,
-                    }
-                ],
-            (CssPreset::Less, pack::PackTool::Webpack) =>
-                vec![
-                    Dependency {
-                        name: "less",
-                        version: "^4.1.3",
-                        mod_type: DependenciesMod::Dev,
-                    },
-                    Dependency {
-                        name: "less-loader",
-                        version: "^11.1.0",
-                        mod_type: DependenciesMod::Dev,
-                    }
-                ],
-            (CssPreset::Sass, pack::PackTool::Vite) =>
-                vec![Dependency {
-                    name: "sass",
-                    version: "^1.77.6",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (CssPreset::Less, pack::PackTool::Vite) =>
-                vec![Dependency {
-                    name: "less",
-                    version: "^4.1.3",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (CssPreset::Sass, pack::PackTool::Rsbuild) =>
-                vec![Dependency {
-                    name: "@rsbuild/plugin-sass",
-                    version: "^1.1.1",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (CssPreset::Less, pack::PackTool::Rsbuild) =>
-                vec![Dependency {
-                    name: "@rsbuild/plugin-less",
-                    version: "^1.1.0",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (CssPreset::Sass, pack::PackTool::Farm) =>
-                vec![Dependency {
-                    name: "@farmfe/plugin-sass",
-                    version: "^1.1.0",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (CssPreset::Less, pack::PackTool::Farm) =>
-                vec![Dependency {
-                    name: "@farmfe/js-plugin-less",
-                    version: "^1.11.0",
-                    mod_type: DependenciesMod::Dev,
-                }],
-            (_, _) => vec![],
+    // CSS-in-JS 方案仅支持 React
+    pub fn is_css_in_js(&self) -> bool {
+        matches!(self, CssPreset::StyledComponents | CssPreset::Emotion)
+    }
+
+    pub fn supported_by(&self, frame: FrameWork) -> bool {
+        !self.is_css_in_js() || frame == FrameWork::React
+    }
+}
+
+pub fn css_selector(
+    css: Option<CssPreset>,
+    pack_tool: pack::PackTool,
+    frame: FrameWork,
+    yes: bool
+) -> Result<CssPreset> {
+    match css {
+        Some(css) if !css.supported_by(frame) => {
+            bail!(ElzaError::InvalidCombination(t!("select.css_unsupported", frame = frame, css = css)))
+        }
+        Some(css) => AnyhowOk(css),
+        None if yes => AnyhowOk(CssPreset::Sass),
+        None => {
+            ensure_interactive("--css")?;
+            logger::pick(&t!("select.css"));
+            let items = css_options(pack_tool, frame);
+            let names: Vec<String> = items.iter().map(CssPreset::to_string).collect();
+            let names: Vec<&str> = names.iter().map(String::as_str).collect();
+            let selection = create_list(&names, 0)?;
+            AnyhowOk(items[selection])
         }
     }
 }
 
-pub fn css_selector() -> Result<CssPreset> {
-    logger::pick("请选择CSS预处理器");
-    let items = vec!["sass", "less", "styled-components"];
-    let selection = create_list(&items, 0)?;
-    match selection {
-        0 => AnyhowOk(CssPreset::Sass),
-        1 => AnyhowOk(CssPreset::Less),
-        _ => {
-            logger::error(&format!("暂不支持: {}", &items[selection]));
-            exit(1);
+// 原子化 CSS
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UtilityCss {
+    Tailwind,
+    Unocss,
+    None,
+}
+
+pub fn utility_css_selector(utility: Option<UtilityCss>, yes: bool) -> Result<UtilityCss> {
+    match utility {
+        Some(utility) => AnyhowOk(utility),
+        None if yes => AnyhowOk(UtilityCss::None),
+        None => {
+            ensure_interactive("--utility")?;
+            logger::pick(&t!("select.utility"));
+            let items = vec!["none", "tailwind", "unocss"];
+            let selection = create_list(&items, 0)?;
+            match selection {
+                1 => AnyhowOk(UtilityCss::Tailwind),
+                2 => AnyhowOk(UtilityCss::Unocss),
+                _ => AnyhowOk(UtilityCss::None),
+            }
+        }
+    }
+}
+
+// 状态管理
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateManagement {
+    Redux,
+    Zustand,
+    Mobx,
+    Jotai,
+    None,
+}
+
+pub fn state_selector(state: Option<StateManagement>, yes: bool) -> Result<StateManagement> {
+    match state {
+        Some(state) => AnyhowOk(state),
+        None if yes => AnyhowOk(StateManagement::None),
+        None => {
+            ensure_interactive("--state")?;
+            logger::pick(&t!("select.state"));
+            let items = vec!["none", "redux toolkit", "zustand", "mobx", "jotai"];
+            let selection = create_list(&items, 0)?;
+            match selection {
+                1 => AnyhowOk(StateManagement::Redux),
+                2 => AnyhowOk(StateManagement::Zustand),
+                3 => AnyhowOk(StateManagement::Mobx),
+                4 => AnyhowOk(StateManagement::Jotai),
+                _ => AnyhowOk(StateManagement::None),
+            }
+        }
+    }
+}
+
+// UI 组件库
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UIDesign {
+    Antd,
+    Arco,
+    Mui,
+    None,
+}
+
+pub fn ui_selector(ui: Option<UIDesign>, yes: bool) -> Result<UIDesign> {
+    match ui {
+        Some(ui) => AnyhowOk(ui),
+        None if yes => AnyhowOk(UIDesign::None),
+        None => {
+            ensure_interactive("--ui")?;
+            logger::pick(&t!("select.ui"));
+            let items = vec!["none", "ant design", "arco design", "mui"];
+            let selection = create_list(&items, 0)?;
+            match selection {
+                1 => AnyhowOk(UIDesign::Antd),
+                2 => AnyhowOk(UIDesign::Arco),
+                3 => AnyhowOk(UIDesign::Mui),
+                _ => AnyhowOk(UIDesign::None),
+            }
         }
     }
 }
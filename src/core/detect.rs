@@ -0,0 +1,240 @@
+use anyhow::{bail, Ok, Result};
+use std::path::Path;
+
+use crate::utils::error::ElzaError;
+
+use super::build::InlineConfig;
+use super::cli::{
+    CodeLanguage, CssPreset, FrameWork, JsLoader, StateManagement, UIDesign, UtilityCss,
+};
+use super::elzarc::ElzaRc;
+use super::pack::PackTool;
+use super::package_json::PackageJson;
+
+// 优先使用生成时记录的配置，旧项目再根据依赖及配置文件推断
+pub fn project_config(project_dir: &Path, pj: &PackageJson) -> Result<InlineConfig> {
+    match ElzaRc::read(project_dir)? {
+        Some(rc) => Ok(rc.config),
+        None => detect_config(project_dir, pj),
+    }
+}
+
+// 根据 package.json 与配置文件反推项目的创建配置
+pub fn detect_config(project_dir: &Path, pj: &PackageJson) -> Result<InlineConfig> {
+    let pack_tool = detect_pack_tool(project_dir, pj)?;
+    let frame = if pj.has_dependency("vue") {
+        FrameWork::Vue
+    } else if pj.has_dependency("react") {
+        FrameWork::React
+    } else {
+        bail!(ElzaError::InvalidCombination(t!("detect.frame_unknown")));
+    };
+    let lang = if pack_tool == PackTool::Farm || project_dir.join("tsconfig.json").is_file() {
+        CodeLanguage::Ts
+    } else {
+        CodeLanguage::Js
+    };
+    let loader = match pack_tool {
+        PackTool::Webpack if project_dir.join(".swcrc").is_file() => JsLoader::Swc,
+        PackTool::Webpack => JsLoader::Babel,
+        _ => JsLoader::None,
+    };
+    Ok(InlineConfig {
+        frame,
+        pack_tool,
+        lang,
+        loader,
+        ui: find_dependency(
+            pj,
+            &[
+                ("antd", UIDesign::Antd),
+                ("@arco-design/web-react", UIDesign::Arco),
+                ("@mui/material", UIDesign::Mui),
+            ],
+        )
+        .unwrap_or(UIDesign::None),
+        state: find_dependency(
+            pj,
+            &[
+                ("@reduxjs/toolkit", StateManagement::Redux),
+                ("zustand", StateManagement::Zustand),
+                ("mobx", StateManagement::Mobx),
+                ("jotai", StateManagement::Jotai),
+            ],
+        )
+        .unwrap_or(StateManagement::None),
+        css: detect_css(pj, pack_tool),
+        utility: find_dependency(
+            pj,
+            &[
+                ("tailwindcss", UtilityCss::Tailwind),
+                ("unocss", UtilityCss::Unocss),
+            ],
+        )
+        .unwrap_or(UtilityCss::None),
+    })
+}
+
+// 优先根据启动脚本判断，其次查找打包工具的配置文件
+fn detect_pack_tool(project_dir: &Path, pj: &PackageJson) -> Result<PackTool> {
+    let tools = [
+        (
+            PackTool::Rsbuild,
+            "rsbuild",
+            &["rsbuild.config.ts", "rsbuild.config.mjs"][..],
+        ),
+        (PackTool::Farm, "farm", &["farm.config.ts"][..]),
+        (
+            PackTool::Vite,
+            "vite",
+            &["vite.config.ts", "vite.config.js"][..],
+        ),
+        (
+            PackTool::Webpack,
+            "webpack",
+            &["scripts/webpack.common.ts", "scripts/webpack.common.js"][..],
+        ),
+        (
+            PackTool::Elza,
+            "elza",
+            &["elza.config.ts", "elza.config.js"][..],
+        ),
+    ];
+    if let Some(script) = pj.script("start") {
+        if let Some((tool, _, _)) = tools.iter().find(|(_, bin, _)| script.contains(bin)) {
+            return Ok(*tool);
+        }
+    }
+    for (tool, _, config_files) in &tools {
+        if config_files
+            .iter()
+            .any(|file| project_dir.join(file).is_file())
+        {
+            return Ok(*tool);
+        }
+    }
+    bail!(ElzaError::InvalidCombination(t!(
+        "detect.pack_tool_unknown"
+    )))
+}
+
+fn detect_css(pj: &PackageJson, pack_tool: PackTool) -> CssPreset {
+    if pack_tool == PackTool::Elza {
+        return CssPreset::None;
+    }
+    // mui 同样依赖 @emotion/styled，需结合 emotion 插件判断
+    let emotion = pj.has_dependency("@emotion/styled")
+        && (!pj.has_dependency("@mui/material")
+            || ["@emotion/babel-plugin", "@swc/plugin-emotion"]
+                .iter()
+                .any(|name| pj.has_dependency(name)));
+    let has_any = |names: &[&str]| names.iter().any(|name| pj.has_dependency(name));
+    if has_any(&["sass", "@rsbuild/plugin-sass", "@farmfe/plugin-sass"]) {
+        CssPreset::Sass
+    } else if has_any(&["less", "@rsbuild/plugin-less", "@farmfe/js-plugin-less"]) {
+        CssPreset::Less
+    } else if pj.has_dependency("styled-components") {
+        CssPreset::StyledComponents
+    } else if emotion {
+        CssPreset::Emotion
+    } else {
+        CssPreset::None
+    }
+}
+
+fn find_dependency<T: Copy>(pj: &PackageJson, candidates: &[(&str, T)]) -> Option<T> {
+    candidates
+        .iter()
+        .find(|(name, _)| pj.has_dependency(name))
+        .map(|(_, value)| *value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::TempDir;
+
+    fn detect(start: &str, dependencies: &[&str], files: &[&str]) -> InlineConfig {
+        let dir = TempDir::new();
+        let dependencies: serde_json::Map<String, serde_json::Value> = dependencies
+            .iter()
+            .map(|name| (name.to_string(), "^1.0.0".into()))
+            .collect();
+        let content = serde_json::json!({
+            "name": "demo",
+            "scripts": { "start": start },
+            "devDependencies": dependencies,
+        });
+        dir.write("package.json", content.to_string());
+        for file in files {
+            dir.write(file, "");
+        }
+        let pj = PackageJson::new(dir.path()).unwrap();
+        detect_config(dir.path(), &pj).unwrap()
+    }
+
+    #[test]
+    fn detects_webpack_with_swc() {
+        let config = detect(
+            "cross-env NODE_ENV=development webpack-dev-server --config ./scripts/webpack.dev.ts",
+            &["react", "react-dom", "webpack", "sass"],
+            &["tsconfig.json", ".swcrc", "scripts/webpack.common.ts"],
+        );
+        assert_eq!(config.pack_tool, PackTool::Webpack);
+        assert_eq!(config.frame, FrameWork::React);
+        assert_eq!(config.lang, CodeLanguage::Ts);
+        assert_eq!(config.loader, JsLoader::Swc);
+        assert_eq!(config.css, CssPreset::Sass);
+    }
+
+    #[test]
+    fn detects_mui_with_emotion_plugin() {
+        let config = detect(
+            "vite",
+            &[
+                "react",
+                "@mui/material",
+                "@emotion/react",
+                "@emotion/styled",
+                "@swc/plugin-emotion",
+            ],
+            &["vite.config.js"],
+        );
+        assert_eq!(config.pack_tool, PackTool::Vite);
+        assert_eq!(config.lang, CodeLanguage::Js);
+        assert_eq!(config.loader, JsLoader::None);
+        assert_eq!(config.ui, UIDesign::Mui);
+        assert_eq!(config.css, CssPreset::Emotion);
+    }
+
+    #[test]
+    fn detects_mui_without_emotion_plugin() {
+        let config = detect(
+            "vite",
+            &[
+                "react",
+                "@mui/material",
+                "@emotion/react",
+                "@emotion/styled",
+            ],
+            &["tsconfig.json"],
+        );
+        assert_eq!(config.ui, UIDesign::Mui);
+        assert_eq!(config.css, CssPreset::None);
+    }
+
+    #[test]
+    fn detects_vue_project() {
+        let config = detect(
+            "rsbuild dev",
+            &["vue", "@rsbuild/core", "@rsbuild/plugin-less", "pinia"],
+            &["rsbuild.config.mjs"],
+        );
+        assert_eq!(config.frame, FrameWork::Vue);
+        assert_eq!(config.pack_tool, PackTool::Rsbuild);
+        assert_eq!(config.lang, CodeLanguage::Js);
+        assert_eq!(config.css, CssPreset::Less);
+        assert_eq!(config.ui, UIDesign::None);
+        assert_eq!(config.state, StateManagement::None);
+    }
+}
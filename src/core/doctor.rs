@@ -0,0 +1,426 @@
+use anyhow::{bail, Result};
+use clap::ValueEnum;
+use console::{pad_str, style, Alignment};
+use reqwest::{Client, Url};
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+use crate::utils::error::ElzaError;
+use crate::utils::{logger, utils};
+
+use super::pack::PackTool;
+use super::package_manager::PackageManager;
+
+// 请求镜像源的超时时间
+const REGISTRY_TIMEOUT: Duration = Duration::from_secs(10);
+
+// 按严重程度排序
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Status {
+    Pass,
+    Warn,
+    Fail,
+}
+
+struct Check {
+    name: String,
+    status: Status,
+    detail: String,
+}
+
+impl Check {
+    fn new(name: impl Into<String>, status: Status, detail: String) -> Self {
+        Self {
+            name: name.into(),
+            status,
+            detail,
+        }
+    }
+}
+
+// 检查创建项目所需的环境，存在失败项时返回错误
+pub async fn doctor(project_dir: &Path) -> Result<()> {
+    logger::info(&t!("doctor.start"));
+    let mut checks = vec![check_git()];
+    checks.extend(check_node());
+    checks.extend(check_package_managers());
+    checks.extend(check_npmrc());
+    checks.extend(check_registry().await);
+    checks.push(check_writable(t!("doctor.current_dir"), project_dir));
+    checks.push(check_writable(t!("doctor.temp_dir"), &std::env::temp_dir()));
+    report(&checks);
+
+    let failed = checks
+        .iter()
+        .filter(|check| check.status == Status::Fail)
+        .count();
+    if failed > 0 {
+        bail!(ElzaError::Environment(t!("doctor.failed", count = failed)));
+    }
+    logger::ready(&t!("doctor.passed"));
+    Ok(())
+}
+
+fn report(checks: &[Check]) {
+    for check in checks {
+        let status = match check.status {
+            Status::Pass => "pass",
+            Status::Warn => "warn",
+            Status::Fail => "fail",
+        };
+        if logger::is_json() {
+            logger::record_info(
+                "check",
+                &format!("{} {}", check.name, check.detail),
+                json!({ "name": check.name, "status": status, "detail": check.detail }),
+            );
+            continue;
+        }
+        let mark = match check.status {
+            Status::Pass => style("✔").green(),
+            Status::Warn => style("!").yellow(),
+            Status::Fail => style("✖").red(),
+        };
+        // 按显示宽度对齐，中文名称占两列
+        let name = pad_str(&check.name, 16, Alignment::Left, None);
+        println!("  {} {} {}", mark, name, check.detail);
+    }
+}
+
+fn check_git() -> Check {
+    match command_version("git") {
+        Some(version) => Check::new("git", Status::Pass, version),
+        None => Check::new("git", Status::Fail, t!("doctor.git_missing")),
+    }
+}
+
+// 按各打包工具要求的最低版本分别检查，全部不满足时视为失败
+fn check_node() -> Vec<Check> {
+    let Some(output) = command_version("node") else {
+        return vec![Check::new("node", Status::Fail, t!("doctor.node_missing"))];
+    };
+    let Some(version) = parse_version(&output) else {
+        return vec![Check::new(
+            "node",
+            Status::Fail,
+            t!("doctor.version_unknown", output = output),
+        )];
+    };
+    let results: Vec<_> = PackTool::value_variants()
+        .iter()
+        .map(|tool| (tool, tool.min_node_version()))
+        .map(|(tool, min)| (tool, min, version >= min))
+        .collect();
+    let supported = results.iter().any(|(_, _, ok)| *ok);
+    results
+        .into_iter()
+        .map(|(tool, min, ok)| {
+            let status = match (ok, supported) {
+                (true, _) => Status::Pass,
+                (false, true) => Status::Warn,
+                (false, false) => Status::Fail,
+            };
+            let min = format_version(min);
+            let detail = if ok {
+                t!("doctor.node_ok", version = output, tool = tool, min = min)
+            } else {
+                t!(
+                    "doctor.node_too_old",
+                    version = output,
+                    tool = tool,
+                    min = min
+                )
+            };
+            Check::new(format!("node ({})", tool), status, detail)
+        })
+        .collect()
+}
+
+// 默认使用的包管理器缺失或低于最低版本时视为失败，其余仅提示
+fn check_package_managers() -> Vec<Check> {
+    let default = PackageManager::detect(None);
+    PackageManager::value_variants()
+        .iter()
+        .map(|pm| {
+            let required = *pm == default;
+            let Some(output) = command_version(&pm.program()) else {
+                return if required {
+                    Check::new(
+                        pm.to_string(),
+                        Status::Fail,
+                        t!("doctor.pm_default_missing"),
+                    )
+                } else {
+                    Check::new(pm.to_string(), Status::Warn, t!("doctor.pm_missing"))
+                };
+            };
+            let min = pm.min_version();
+            match parse_version(&output) {
+                Some(version) if version >= min => Check::new(
+                    pm.to_string(),
+                    Status::Pass,
+                    t!("doctor.pm_ok", version = output, min = format_version(min)),
+                ),
+                Some(_) => Check::new(
+                    pm.to_string(),
+                    if required { Status::Fail } else { Status::Warn },
+                    t!(
+                        "doctor.pm_too_old",
+                        version = output,
+                        min = format_version(min)
+                    ),
+                ),
+                None => Check::new(
+                    pm.to_string(),
+                    Status::Warn,
+                    t!("doctor.version_unknown", output = output),
+                ),
+            }
+        })
+        .collect()
+}
+
+// 检查 ~/.npmrc 的格式，未配置时使用默认镜像源
+fn check_npmrc() -> Vec<Check> {
+    let Some(path) = utils::npmrc_path() else {
+        return vec![Check::new(
+            ".npmrc",
+            Status::Warn,
+            t!("npmrc.no_home", registry = utils::DEFAULT_NPM_REGISTRY),
+        )];
+    };
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            return vec![Check::new(
+                ".npmrc",
+                Status::Pass,
+                t!("npmrc.not_found", registry = utils::DEFAULT_NPM_REGISTRY),
+            )];
+        }
+        Err(e) => {
+            return vec![Check::new(
+                ".npmrc",
+                Status::Fail,
+                t!(
+                    "npmrc.open_failed",
+                    error = e,
+                    registry = utils::DEFAULT_NPM_REGISTRY
+                ),
+            )];
+        }
+    };
+    let problems = validate_npmrc(&content);
+    if problems.is_empty() {
+        return vec![Check::new(
+            ".npmrc",
+            Status::Pass,
+            path.display().to_string(),
+        )];
+    }
+    problems
+        .into_iter()
+        .map(|(status, detail)| Check::new(".npmrc", status, detail))
+        .collect()
+}
+
+// 逐行检查缺少键名的行、重复的键、镜像源地址及引用的环境变量
+fn validate_npmrc(content: &str) -> Vec<(Status, String)> {
+    let mut problems = vec![];
+    let mut keys = BTreeSet::new();
+    for (index, line) in content.lines().enumerate() {
+        let line_no = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        // ini 格式中只有键的行表示 true
+        let (key, value) = line
+            .split_once('=')
+            .map_or((line, "true"), |(key, value)| (key.trim(), value.trim()));
+        if key.is_empty() {
+            problems.push((Status::Warn, t!("doctor.npmrc_malformed", line = line_no)));
+            continue;
+        }
+        if !keys.insert(key) {
+            problems.push((
+                Status::Warn,
+                t!("doctor.npmrc_duplicate", line = line_no, key = key),
+            ));
+        }
+        if (key == "registry" || key.ends_with(":registry")) && !is_http_url(value) {
+            problems.push((
+                Status::Fail,
+                t!(
+                    "doctor.npmrc_invalid_url",
+                    line = line_no,
+                    key = key,
+                    value = value
+                ),
+            ));
+        }
+        // npm 读取配置时会替换 ${NAME}，变量未定义时保留原文，认证信息等随之失效
+        for name in env_references(value) {
+            if env::var_os(name).is_none() {
+                problems.push((
+                    Status::Warn,
+                    t!("doctor.npmrc_unset_env", line = line_no, name = name),
+                ));
+            }
+        }
+    }
+    problems
+}
+
+fn env_references(value: &str) -> Vec<&str> {
+    value
+        .split("${")
+        .skip(1)
+        .filter_map(|rest| rest.split_once('}').map(|(name, _)| name))
+        // ${NAME?} 未定义时替换为空字符串
+        .filter(|name| !name.is_empty() && !name.ends_with('?'))
+        .collect()
+}
+
+fn is_http_url(value: &str) -> bool {
+    Url::parse(value)
+        .map(|url| matches!(url.scheme(), "http" | "https"))
+        .unwrap_or(false)
+}
+
+// 请求 npm 的 /-/ping 接口，私有镜像源不一定代理公共包
+async fn check_registry() -> Vec<Check> {
+    let registry = utils::get_user_npm_registry();
+    if !is_http_url(&registry) {
+        return vec![Check::new(
+            "registry",
+            Status::Fail,
+            t!("doctor.registry_invalid", registry = registry),
+        )];
+    }
+    let client = match Client::builder().timeout(REGISTRY_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => return vec![Check::new("registry", Status::Fail, e.to_string())],
+    };
+    let url = format!("{}/-/ping", registry.trim_end_matches('/'));
+    logger::debug(&t!("registry.request", url = url));
+    let check = match client.get(&url).send().await {
+        Ok(response) if response.status().is_success() => {
+            Check::new("registry", Status::Pass, registry)
+        }
+        // 能够连接但需要认证等情况仅提示
+        Ok(response) => Check::new(
+            "registry",
+            Status::Warn,
+            t!(
+                "doctor.registry_bad_response",
+                registry = registry,
+                status = response.status()
+            ),
+        ),
+        Err(e) => Check::new(
+            "registry",
+            Status::Fail,
+            t!(
+                "doctor.registry_unreachable",
+                registry = registry,
+                error = e
+            ),
+        ),
+    };
+    vec![check]
+}
+
+// 写入并删除一个临时文件
+fn check_writable(name: String, dir: &Path) -> Check {
+    let probe = dir.join(format!(".elza-doctor-{}", std::process::id()));
+    let result = fs::write(&probe, b"").and_then(|_| fs::remove_file(&probe));
+    let dir = fs::canonicalize(dir).unwrap_or_else(|_| dir.to_path_buf());
+    match result {
+        Ok(()) => Check::new(name, Status::Pass, dir.display().to_string()),
+        Err(e) => Check::new(
+            name,
+            Status::Fail,
+            t!("doctor.not_writable", path = dir.display(), error = e),
+        ),
+    }
+}
+
+// 执行 `<program> --version`，返回输出的第一行
+fn command_version(program: &str) -> Option<String> {
+    logger::debug(&t!(
+        "common.run_command",
+        command = format!("{} --version", program)
+    ));
+    let output = Command::new(program)
+        .arg("--version")
+        .stdin(Stdio::null())
+        .output()
+        .ok()
+        .filter(|output| output.status.success())?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout
+        .lines()
+        .next()
+        .map(|line| line.trim().to_string())
+        .filter(|line| !line.is_empty())
+}
+
+// 从 "v20.12.2"、"git version 2.43.0" 等输出中取出版本号
+fn parse_version(output: &str) -> Option<(u64, u64, u64)> {
+    let token = output
+        .split_whitespace()
+        .map(|word| word.trim_start_matches('v'))
+        .find(|word| word.starts_with(|c: char| c.is_ascii_digit()))?;
+    let mut parts = token
+        .split(['.', '-', '+'])
+        .map(|part| part.parse::<u64>().ok());
+    Some((
+        parts.next()??,
+        parts.next().flatten().unwrap_or(0),
+        parts.next().flatten().unwrap_or(0),
+    ))
+}
+
+fn format_version(version: (u64, u64, u64)) -> String {
+    format!("{}.{}.{}", version.0, version.1, version.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_npmrc_lines() {
+        let content = "\
+# comment
+registry=https://registry.npmmirror.com/
+@corp:registry = not-a-url
+//npm.corp.com/:_authToken=${ELZA_DOCTOR_UNSET_TOKEN}
+always-auth=${ELZA_DOCTOR_OPTIONAL?}
+=oops
+strict-ssl
+registry=https://registry.npmjs.org/
+";
+        let statuses: Vec<Status> = validate_npmrc(content)
+            .into_iter()
+            .map(|(status, _)| status)
+            .collect();
+        assert_eq!(
+            statuses,
+            [Status::Fail, Status::Warn, Status::Warn, Status::Warn]
+        );
+    }
+
+    #[test]
+    fn accepts_valid_npmrc() {
+        let content = "registry=https://registry.npmjs.org/\n; note\n\nsave-exact=true\n";
+        assert!(validate_npmrc(content).is_empty());
+    }
+}
@@ -0,0 +1,137 @@
+use console::style;
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::utils::logger;
+
+use super::build::ProjectPlan;
+use super::manifest::{DependenciesMod, Dependency};
+use super::package_json::PackageJson;
+
+// 目录树节点，note 为空的节点为目录
+#[derive(Default)]
+struct TreeNode {
+    children: BTreeMap<String, TreeNode>,
+    note: Option<String>,
+}
+
+impl TreeNode {
+    fn insert(&mut self, path: &str, note: String) {
+        let node = path.split('/').fold(self, |node, part| {
+            node.children.entry(part.to_string()).or_default()
+        });
+        node.note = Some(note);
+    }
+
+    fn print(&self, prefix: &str) {
+        let count = self.children.len();
+        for (index, (name, child)) in self.children.iter().enumerate() {
+            let last = index + 1 == count;
+            let branch = if last { "└── " } else { "├── " };
+            match &child.note {
+                Some(note) => println!("{}{}{}  {}", prefix, branch, name, note),
+                None => println!("{}{}{}/", prefix, branch, style(name).blue()),
+            }
+            child.print(&format!("{}{}", prefix, if last { "    " } else { "│   " }));
+        }
+    }
+}
+
+// 输出预览结果：文件树及来源模板层、被跳过的文件、按来源分组的依赖及最终的 package.json
+pub fn print_plan(
+    project_dir: &Path,
+    plan: &ProjectPlan,
+    pj: &PackageJson,
+    dependencies: &[Dependency],
+) {
+    if logger::is_json() {
+        return record_plan(project_dir, plan, pj, dependencies);
+    }
+    logger::info(&t!("dry_run.notice"));
+    let mut tree = TreeNode::default();
+    for (target, (layer, _)) in &plan.files {
+        tree.insert(target, style(layer).dim().to_string());
+    }
+    for (target, layer) in &plan.skipped {
+        if !plan.files.contains_key(target) {
+            tree.insert(
+                target,
+                style(t!("dry_run.skipped", layer = layer))
+                    .yellow()
+                    .to_string(),
+            );
+        }
+    }
+    println!("{}/", style(project_dir.display()).blue());
+    tree.print("");
+
+    // 按声明依赖的模板清单分组，保持清单中的顺序
+    let mut groups: Vec<(&str, Vec<&Dependency>)> = vec![];
+    for dep in dependencies {
+        match groups.iter_mut().find(|(source, _)| *source == dep.source) {
+            Some((_, deps)) => deps.push(dep),
+            None => groups.push((&dep.source, vec![dep])),
+        }
+    }
+    logger::info(&t!("dry_run.dependencies"));
+    for (source, deps) in groups {
+        println!("  {}", style(source).cyan());
+        for dep in deps {
+            let mod_type = match dep.mod_type {
+                DependenciesMod::Dev => "dev",
+                DependenciesMod::Prod => "prod",
+            };
+            println!(
+                "    {} {} {}",
+                dep.name,
+                style(&dep.version).green(),
+                style(mod_type).dim()
+            );
+        }
+    }
+    logger::info("package.json:");
+    println!("{}", pj.content());
+}
+
+// json 日志格式下整个预览结果作为一条事件输出
+fn record_plan(
+    project_dir: &Path,
+    plan: &ProjectPlan,
+    pj: &PackageJson,
+    dependencies: &[Dependency],
+) {
+    let files: Vec<_> = plan
+        .files
+        .iter()
+        .map(|(target, (layer, _))| json!({ "path": target, "layer": layer }))
+        .collect();
+    let skipped: Vec<_> = plan
+        .skipped
+        .iter()
+        .filter(|(target, _)| !plan.files.contains_key(target))
+        .map(|(target, layer)| json!({ "path": target, "layer": layer }))
+        .collect();
+    let dependencies: Vec<_> = dependencies
+        .iter()
+        .map(|dep| {
+            json!({
+                "name": dep.name,
+                "version": dep.version,
+                "dependencyType": dep.mod_type,
+                "source": dep.source,
+            })
+        })
+        .collect();
+    logger::record_info(
+        "plan",
+        &t!("dry_run.notice"),
+        json!({
+            "path": project_dir,
+            "files": files,
+            "skipped": skipped,
+            "dependencies": dependencies,
+            "packageJson": pj.json(),
+        }),
+    );
+}
@@ -0,0 +1,114 @@
+use anyhow::{bail, Context, Ok, Result};
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::utils::error::ElzaError;
+use crate::utils::logger;
+
+use super::build::{
+    render_project, write_project_file, InlineConfig, Templates, TEMPLATE_OVERLAY_ENV,
+};
+use super::manifest::TemplateManifest;
+use super::source::TEMPLATE_MANIFEST_FILE;
+
+// 项目名称保留为模板变量，使用导出的模板创建项目时再渲染
+const NAME_PLACEHOLDER: &str = "{%= name %}";
+
+// 导出内置模板层的原始文件，目录结构与模板覆盖目录一致
+pub fn eject_layer(layer: &str, out: Option<PathBuf>) -> Result<()> {
+    let layer = layer.trim_matches('/');
+    let layers = builtin_layers()?;
+    if !layers.contains(layer) {
+        bail!(ElzaError::InvalidCombination(t!(
+            "eject.layer_not_found",
+            layer = layer,
+            layers = layers.into_iter().collect::<Vec<_>>().join(", ")
+        )));
+    }
+    let out = out.unwrap_or_else(|| PathBuf::from("elza-templates"));
+    let target = out.join(layer);
+    ensure_empty_dir(&target)?;
+
+    let prefix = format!("{}/", layer);
+    for path in Templates::iter() {
+        let Some(filename) = path.strip_prefix(&prefix) else {
+            continue;
+        };
+        let file = Templates::get(&path)
+            .with_context(|| ElzaError::Template(t!("template.read_failed", path = path)))?;
+        write_project_file(&target, filename, &file.data)?;
+    }
+    logger::ready(&t!(
+        "eject.layer_done",
+        layer = layer,
+        path = target.display()
+    ));
+    logger::info(&t!(
+        "eject.overlay_hint",
+        env = TEMPLATE_OVERLAY_ENV,
+        path = out.display()
+    ));
+    Ok(())
+}
+
+// 导出指定配置渲染后的完整模板，附带模板清单，可通过 --from 使用
+pub fn eject_project(config: InlineConfig, out: Option<PathBuf>) -> Result<()> {
+    let manifest = TemplateManifest::find(config.frame, config.pack_tool, config.lang)?;
+    let out = out.unwrap_or_else(|| {
+        PathBuf::from(format!(
+            "{}-{}-{}",
+            config.get_var("packTool").unwrap_or_default(),
+            config.get_var("frame").unwrap_or_default(),
+            config.get_var("lang").unwrap_or_default()
+        ))
+    });
+    ensure_empty_dir(&out)?;
+
+    let context = manifest.get_context(&config, NAME_PLACEHOLDER)?;
+    let files = render_project(&manifest, config, &context)?;
+    for (target, data) in &files {
+        write_project_file(&out, target, data)?;
+    }
+    let dependencies: Vec<_> = manifest
+        .get_dependencies(&config)?
+        .into_iter()
+        .map(|dep| json!({ "name": dep.name, "version": dep.version, "type": dep.mod_type }))
+        .collect();
+    let content = json!({
+        "name": manifest.name,
+        "frame": config.frame,
+        "packTool": config.pack_tool,
+        "lang": [config.lang],
+        "package": manifest.package,
+        "dependencies": dependencies,
+        "tips": manifest.tips,
+    });
+    fs::write(
+        out.join(TEMPLATE_MANIFEST_FILE),
+        serde_json::to_string_pretty(&content)? + "\n",
+    )
+    .with_context(|| ElzaError::Io(t!("file.write_failed", path = TEMPLATE_MANIFEST_FILE)))?;
+
+    logger::ready(&t!("eject.done", path = out.display()));
+    logger::info(&t!("eject.from_hint", path = out.display()));
+    Ok(())
+}
+
+// 所有模板清单中用到的内置模板层
+fn builtin_layers() -> Result<BTreeSet<String>> {
+    Ok(TemplateManifest::all()?
+        .into_iter()
+        .flat_map(|manifest| manifest.layers)
+        .map(|layer| layer.dir)
+        .collect())
+}
+
+// 避免覆盖已有文件，目标目录须不存在或为空
+fn ensure_empty_dir(dir: &Path) -> Result<()> {
+    if dir.is_file() || (dir.is_dir() && fs::read_dir(dir)?.next().is_some()) {
+        bail!(ElzaError::Io(t!("eject.not_empty", path = dir.display())));
+    }
+    Ok(())
+}
@@ -0,0 +1,229 @@
+use anyhow::{Context, Ok, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use crate::utils::error::ElzaError;
+
+use super::build::{overlay_dir, InlineConfig, Templates};
+use super::manifest::{Dependency, TemplateManifest};
+use super::source::list_files;
+
+// 项目元数据文件名，位于项目根目录
+pub const ELZARC_FILE: &str = ".elzarc.json";
+// 最近一次生成的模板文件，作为 upgrade 三方合并的基准
+pub const BASE_DIR: &str = ".elza/base";
+
+// 项目的生成信息，供后续命令识别用户修改及安全升级
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ElzaRc {
+    // 生成项目时的 elza-cli 版本
+    pub version: String,
+    pub config: InlineConfig,
+    // 模板的摘要，模板内容有任何改动都会变化
+    pub template_revision: String,
+    // 通过 --from 创建时的外部模板来源
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    // 生成文件的 sha256，键为项目内的相对路径
+    pub files: BTreeMap<String, String>,
+    // 生成时写入 package.json 的依赖版本
+    #[serde(default)]
+    pub dependencies: BTreeMap<String, String>,
+}
+
+impl ElzaRc {
+    pub fn new(
+        config: InlineConfig,
+        manifest: &TemplateManifest,
+        files: &BTreeMap<String, Vec<u8>>,
+        dependencies: &[Dependency],
+    ) -> Result<Self> {
+        Ok(Self {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            config,
+            template_revision: template_revision(manifest)?,
+            source: manifest.source.clone(),
+            files: hash_files(files),
+            dependencies: dependencies
+                .iter()
+                .map(|dep| (dep.name.clone(), dep.version.clone()))
+                .collect(),
+        })
+    }
+
+    // 项目中没有元数据文件时返回 None
+    pub fn read(project_dir: &Path) -> Result<Option<Self>> {
+        let path = project_dir.join(ELZARC_FILE);
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&path)
+            .with_context(|| ElzaError::Io(t!("file.read_failed", path = ELZARC_FILE)))?;
+        let rc = serde_json::from_str(&content)
+            .with_context(|| ElzaError::Io(t!("file.parse_failed", path = ELZARC_FILE)))?;
+        Ok(Some(rc))
+    }
+
+    pub fn write(&self, project_dir: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        fs::write(project_dir.join(ELZARC_FILE), content + "\n")
+            .with_context(|| ElzaError::Io(t!("file.write_failed", path = ELZARC_FILE)))?;
+        Ok(())
+    }
+
+    // 以磁盘上的当前内容更新文件摘要，用于生成后又被 cli 修改过的文件，如 package.json
+    pub fn record_file(&mut self, project_dir: &Path, path: &str) -> Result<()> {
+        let data = fs::read(project_dir.join(path))
+            .with_context(|| ElzaError::Io(t!("file.read_failed", path = path)))?;
+        self.files.insert(path.to_string(), hash_content(&data));
+        Ok(())
+    }
+
+    // 文件内容与记录的生成结果一致，即未被用户修改
+    pub fn is_unmodified(&self, path: &str, data: &[u8]) -> bool {
+        self.files
+            .get(path)
+            .is_some_and(|hash| *hash == hash_content(data))
+    }
+}
+
+pub fn hash_files(files: &BTreeMap<String, Vec<u8>>) -> BTreeMap<String, String> {
+    files
+        .iter()
+        .map(|(path, data)| (path.clone(), hash_content(data)))
+        .collect()
+}
+
+pub fn hash_content(data: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(data))
+}
+
+// 汇总所用模板的清单、各模板层及其覆盖目录中的文件，取前 12 位作为模板版本
+pub fn template_revision(manifest: &TemplateManifest) -> Result<String> {
+    let mut hasher = Sha256::new();
+    for path in &manifest.manifest_files {
+        if let Some(file) = Templates::get(path) {
+            hasher.update(path.as_bytes());
+            hasher.update(&file.data);
+        }
+    }
+    // 外部模板的清单及模板层都在根目录中
+    if let Some(root) = &manifest.root {
+        for path in list_files(root)? {
+            hasher.update(path.as_bytes());
+            hasher.update(fs::read(root.join(&path))?);
+        }
+    }
+    let overlay_dir = overlay_dir();
+    for layer in manifest.layers.iter().filter(|layer| layer.root.is_none()) {
+        let prefix = format!("{}/", layer.dir);
+        let mut paths: Vec<_> = Templates::iter()
+            .filter(|path| path.starts_with(&prefix))
+            .collect();
+        paths.sort();
+        for path in paths {
+            if let Some(file) = Templates::get(&path) {
+                hasher.update(path.as_bytes());
+                hasher.update(&file.data);
+            }
+        }
+        let Some(dir) = overlay_dir
+            .as_ref()
+            .map(|dir| dir.join(&layer.dir))
+            .filter(|dir| dir.is_dir())
+        else {
+            continue;
+        };
+        for path in list_files(&dir)? {
+            hasher.update(format!("overlay/{}/{}", layer.dir, path).as_bytes());
+            hasher.update(fs::read(dir.join(&path))?);
+        }
+    }
+    Ok(format!("{:x}", hasher.finalize())[..12].to_string())
+}
+
+// 用本次生成的全部文件替换合并基准
+pub fn write_base(project_dir: &Path, files: &BTreeMap<String, Vec<u8>>) -> Result<()> {
+    let base_dir = project_dir.join(BASE_DIR);
+    if base_dir.exists() {
+        fs::remove_dir_all(&base_dir)
+            .with_context(|| ElzaError::Io(t!("file.clean_failed", path = BASE_DIR)))?;
+    }
+    for (path, data) in files {
+        update_base(project_dir, path, Some(data))?;
+    }
+    Ok(())
+}
+
+// 更新合并基准中的单个文件，data 为空时删除
+pub fn update_base(project_dir: &Path, path: &str, data: Option<&[u8]>) -> Result<()> {
+    let file_path = project_dir.join(BASE_DIR).join(path);
+    match data {
+        Some(data) => {
+            if let Some(parent) = file_path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(&file_path, data)
+                .with_context(|| ElzaError::Io(t!("elzarc.base_write_failed", path = path)))?;
+        }
+        None if file_path.exists() => fs::remove_file(&file_path)?,
+        None => {}
+    }
+    Ok(())
+}
+
+// 读取合并基准，旧项目没有基准时返回空
+pub fn read_base(project_dir: &Path) -> Result<BTreeMap<String, Vec<u8>>> {
+    let base_dir = project_dir.join(BASE_DIR);
+    let mut files = BTreeMap::new();
+    for path in list_files(&base_dir)? {
+        let data = fs::read(base_dir.join(&path))?;
+        files.insert(path, data);
+    }
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::cli::{CodeLanguage, FrameWork};
+    use crate::core::pack::PackTool;
+    use crate::utils::test_utils::TempDir;
+
+    #[test]
+    fn revision_covers_only_the_used_manifest() {
+        let manifest =
+            TemplateManifest::find(FrameWork::React, PackTool::Webpack, CodeLanguage::Ts).unwrap();
+        assert_eq!(
+            manifest.manifest_files,
+            [
+                "manifests/webpack-react-ts.json",
+                "manifests/shared/react.json",
+                "manifests/shared/common.json",
+                "manifests/shared/webpack.json",
+            ]
+        );
+        let vue =
+            TemplateManifest::find(FrameWork::Vue, PackTool::Webpack, CodeLanguage::Ts).unwrap();
+        assert_ne!(
+            template_revision(&manifest).unwrap(),
+            template_revision(&vue).unwrap()
+        );
+    }
+
+    #[test]
+    fn revision_follows_external_template_files() {
+        let dir = TempDir::new();
+        dir.write("src/index.js", "console.log(1);\n");
+        let manifest = TemplateManifest::load_external(dir.path(), "local").unwrap();
+        let before = template_revision(&manifest).unwrap();
+        assert_eq!(before, template_revision(&manifest).unwrap());
+
+        dir.write("src/index.js", "console.log(2);\n");
+        assert_ne!(before, template_revision(&manifest).unwrap());
+    }
+}
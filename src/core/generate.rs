@@ -0,0 +1,230 @@
+use anyhow::{bail, Context, Ok, Result};
+use clap::ValueEnum;
+use std::fs;
+use std::path::Path;
+
+use crate::utils::error::ElzaError;
+use crate::utils::logger;
+
+use super::build::{write_project_file, InlineConfig, Templates};
+use super::cli::{CodeLanguage, CssPreset, FrameWork};
+use super::detect::project_config;
+use super::manifest::TemplateManifest;
+use super::pack::PackTool;
+use super::package_json::PackageJson;
+use super::template::{render, TemplateContext, TemplateValue};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum GenerateKind {
+    Page,
+    Component,
+}
+
+// 在当前项目中生成页面或组件，页面需要时同时注册路由
+pub fn generate(project_dir: &Path, kind: GenerateKind, name: &str) -> Result<()> {
+    let segments = parse_name(name)?;
+    let pj = PackageJson::new(project_dir)?;
+    let config = project_config(project_dir, &pj)?;
+    let manifest = TemplateManifest::find(config.frame, config.pack_tool, config.lang)?;
+    let mut context = manifest.get_context(&config, pj.name())?;
+
+    let last = segments.last().map(String::as_str).unwrap_or_default();
+    let component_name = to_pascal_case(last);
+    let dir = match kind {
+        GenerateKind::Page => format!("src/pages/{}", segments.join("/")),
+        GenerateKind::Component => format!("src/components/{}", component_name),
+    };
+    let kind_name = match kind {
+        GenerateKind::Page => "page",
+        GenerateKind::Component => "component",
+    };
+    let class_name = format!("{}-{}", last, kind_name);
+    for (key, value) in [
+        ("kind", kind_name),
+        ("componentName", &component_name),
+        ("className", &class_name),
+    ] {
+        context.insert(key.to_string(), TemplateValue::Str(value.to_string()));
+    }
+
+    let files = match config.frame {
+        FrameWork::React => react_files(&config, &dir, &mut context),
+        FrameWork::Vue => vue_files(&config, &dir, &mut context),
+    };
+    for (_, target) in &files {
+        if project_dir.join(target).exists() {
+            bail!(ElzaError::Io(t!("generate.exists", path = target)));
+        }
+    }
+    for (source, target) in &files {
+        let file = Templates::get(source)
+            .with_context(|| ElzaError::Template(t!("template.read_failed", path = source)))?;
+        let text = std::str::from_utf8(&file.data)?;
+        let data = render(text, &context)
+            .with_context(|| ElzaError::Template(t!("template.render_failed", path = source)))?;
+        write_project_file(project_dir, target, data.as_bytes())?;
+    }
+
+    if kind == GenerateKind::Page {
+        if uses_auto_routes(&config, &pj) {
+            logger::info(&t!("generate.auto_routes"));
+        } else {
+            register_route(project_dir, &config, &segments)?;
+        }
+    }
+    logger::ready(&t!("generate.done", path = dir));
+    Ok(())
+}
+
+// 名称支持 a/b 形式的嵌套路径，每一段统一转换为 kebab-case
+fn parse_name(name: &str) -> Result<Vec<String>> {
+    let segments: Vec<String> = name
+        .trim_matches('/')
+        .split('/')
+        .map(to_kebab_case)
+        .collect();
+    let valid = |segment: &String| {
+        !segment.is_empty()
+            && segment
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-')
+            && segment.starts_with(|c: char| c.is_ascii_alphabetic())
+    };
+    if !segments.iter().all(valid) {
+        bail!(ElzaError::InvalidCombination(t!(
+            "generate.invalid_name",
+            name = name
+        )));
+    }
+    Ok(segments)
+}
+
+// React 使用 CSS Modules，css-in-js 方案直接使用 styled，未选择预处理器时使用普通样式文件
+fn react_files(
+    config: &InlineConfig,
+    dir: &str,
+    context: &mut TemplateContext,
+) -> Vec<(&'static str, String)> {
+    let ext = match config.lang {
+        CodeLanguage::Ts => "tsx",
+        CodeLanguage::Js => "jsx",
+    };
+    let mut files = vec![(
+        "generators/react/index.jsx",
+        format!("{}/index.{}", dir, ext),
+    )];
+    let style_file = match config.css {
+        CssPreset::Sass => "index.module.scss",
+        CssPreset::Less => "index.module.less",
+        CssPreset::StyledComponents | CssPreset::Emotion => "",
+        CssPreset::None => "index.css",
+    };
+    // 模板条件中的变量须有定义，不使用 CSS Modules 时置空
+    let css_module = if matches!(config.css, CssPreset::Sass | CssPreset::Less) {
+        "true"
+    } else {
+        ""
+    };
+    for (key, value) in [("styleFile", style_file), ("cssModule", css_module)] {
+        context.insert(key.to_string(), TemplateValue::Str(value.to_string()));
+    }
+    if style_file.is_empty() {
+        return files;
+    }
+    files.push((
+        "generators/react/style.css",
+        format!("{}/{}", dir, style_file),
+    ));
+    files
+}
+
+// Vue 单文件组件，样式写在 scoped style 中
+fn vue_files(
+    config: &InlineConfig,
+    dir: &str,
+    context: &mut TemplateContext,
+) -> Vec<(&'static str, String)> {
+    let style_lang = match config.css {
+        CssPreset::Sass => "scss",
+        CssPreset::Less => "less",
+        _ => "",
+    };
+    context.insert(
+        "styleLang".to_string(),
+        TemplateValue::Str(style_lang.to_string()),
+    );
+    vec![("generators/vue/index.vue", format!("{}/index.vue", dir))]
+}
+
+// elza 内置约定式路由，其余打包工具通过 auto-routes 插件生成路由
+fn uses_auto_routes(config: &InlineConfig, pj: &PackageJson) -> bool {
+    config.pack_tool == PackTool::Elza
+        || ["webpack-plugin-auto-routes", "farm-plugin-auto-routes"]
+            .iter()
+            .any(|name| pj.has_dependency(name))
+}
+
+// 在兜底路由之前插入新页面的路由
+fn register_route(project_dir: &Path, config: &InlineConfig, segments: &[String]) -> Result<()> {
+    if config.frame != FrameWork::Vue {
+        logger::warning(&t!("generate.no_router"));
+        return Ok(());
+    }
+    let router = match config.lang {
+        CodeLanguage::Ts => "src/router/index.ts",
+        CodeLanguage::Js => "src/router/index.js",
+    };
+    let router_path = project_dir.join(router);
+    let Some(mut content) = fs::read_to_string(&router_path).ok() else {
+        logger::warning(&t!("generate.router_missing", path = router));
+        return Ok(());
+    };
+    let path = segments.join("/");
+    let route = format!(
+        "  {{ path: '/{}', component: () => import('../pages/{}/index.vue') }},\n",
+        path, path
+    );
+    let anchor = content
+        .find("  { path: '/:pathMatch")
+        .or_else(|| content.find("];"));
+    let Some(index) = anchor else {
+        logger::warning(&t!("generate.routes_missing", path = router));
+        return Ok(());
+    };
+    content.insert_str(index, &route);
+    logger::event(&t!("generate.register_route", route = path));
+    fs::write(&router_path, content)
+        .with_context(|| ElzaError::Io(t!("file.write_failed", path = router)))?;
+    Ok(())
+}
+
+fn to_kebab_case(segment: &str) -> String {
+    let mut result = String::new();
+    for (index, c) in segment.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if index > 0 && !result.ends_with('-') {
+                result.push('-');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else if c == '_' {
+            result.push('-');
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
+
+fn to_pascal_case(segment: &str) -> String {
+    segment
+        .split('-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
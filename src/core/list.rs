@@ -0,0 +1,180 @@
+use anyhow::Result;
+use clap::ValueEnum;
+use console::{measure_text_width, pad_str, style, Alignment};
+use serde_json::{json, Value};
+use std::path::Path;
+
+use crate::utils::logger;
+
+use super::build::{apply_package_json, plan_project, InlineConfig};
+use super::cli::{
+    css_options, lang_options, loader_options, state_options, ui_options, utility_options,
+    FrameWork, StateManagement, UIDesign, UtilityCss,
+};
+use super::manifest::{DependenciesMod, Dependency, TemplateManifest};
+use super::pack::PackTool;
+
+// 渲染模板时使用的项目名称，不出现在输出中
+const PROJECT_NAME: &str = "elza-app";
+
+// 一个有效的选项组合及其生成结果
+struct Combination {
+    template: String,
+    config: InlineConfig,
+    scripts: Value,
+    dependencies: Vec<Dependency>,
+}
+
+// 列出所有有效的框架、打包工具、语言、loader 及样式方案组合
+pub fn list_templates(as_json: bool) -> Result<()> {
+    let combinations = combinations()?;
+    if as_json {
+        let items: Vec<_> = combinations.iter().map(to_json).collect();
+        println!("{}", serde_json::to_string_pretty(&items)?);
+        return Ok(());
+    }
+    print_table(&combinations);
+    logger::info(&t!("list.total", count = combinations.len()));
+    logger::info(&t!("list.options_hint"));
+    Ok(())
+}
+
+// 按 select_config 的规则展开各选项，渲染模板并生成 package.json，与创建项目时一致
+// 原子化 CSS、状态管理及组件库不参与组合，使用默认值
+fn combinations() -> Result<Vec<Combination>> {
+    let manifests = TemplateManifest::all()?;
+    let mut combinations = vec![];
+    for &frame in FrameWork::value_variants() {
+        for &pack_tool in PackTool::value_variants() {
+            for &lang in lang_options(pack_tool) {
+                let Some(manifest) = manifests
+                    .iter()
+                    .find(|manifest| manifest.matches(frame, pack_tool, lang))
+                else {
+                    continue;
+                };
+                for &loader in loader_options(pack_tool) {
+                    for css in css_options(pack_tool, frame) {
+                        let config = InlineConfig {
+                            frame,
+                            pack_tool,
+                            lang,
+                            loader,
+                            css,
+                            utility: UtilityCss::None,
+                            state: StateManagement::None,
+                            ui: UIDesign::None,
+                        };
+                        combinations.push(build_combination(manifest, config)?);
+                    }
+                }
+            }
+        }
+    }
+    Ok(combinations)
+}
+
+fn build_combination(manifest: &TemplateManifest, config: InlineConfig) -> Result<Combination> {
+    let context = manifest.get_context(&config, PROJECT_NAME)?;
+    let plan = plan_project(manifest, config, &context)?;
+    let mut pj = plan.package_json(Path::new(PROJECT_NAME))?;
+    let dependencies = manifest.get_dependencies(&config)?;
+    apply_package_json(&mut pj, PROJECT_NAME, manifest, &dependencies)?;
+    Ok(Combination {
+        template: manifest.name.clone(),
+        config,
+        scripts: pj.json()["scripts"].clone(),
+        dependencies,
+    })
+}
+
+fn to_json(combination: &Combination) -> Value {
+    let config = combination.config;
+    let dependencies: Vec<_> = combination
+        .dependencies
+        .iter()
+        .map(|dep| json!({ "name": dep.name, "version": dep.version, "dependencyType": dep.mod_type }))
+        .collect();
+    json!({
+        "template": combination.template,
+        "frame": config.frame,
+        "packTool": config.pack_tool,
+        "lang": config.lang,
+        "loader": config.loader,
+        "css": config.css,
+        "options": {
+            "utility": utility_options(config.pack_tool),
+            "state": state_options(config.frame),
+            "ui": ui_options(config.frame),
+        },
+        "scripts": combination.scripts,
+        "dependencies": dependencies,
+    })
+}
+
+fn print_table(combinations: &[Combination]) {
+    let header = vec![
+        t!("list.template"),
+        t!("list.frame"),
+        t!("list.pack_tool"),
+        t!("list.lang"),
+        "loader".to_string(),
+        t!("list.css"),
+        t!("list.dependencies"),
+        t!("list.scripts"),
+    ];
+    let rows: Vec<Vec<String>> = combinations
+        .iter()
+        .map(|combination| {
+            let config = combination.config;
+            let dev = combination
+                .dependencies
+                .iter()
+                .filter(|dep| matches!(dep.mod_type, DependenciesMod::Dev))
+                .count();
+            let scripts = combination
+                .scripts
+                .as_object()
+                .map(|scripts| scripts.keys().cloned().collect::<Vec<_>>().join(", "))
+                .unwrap_or_default();
+            vec![
+                combination.template.clone(),
+                config.get_var("frame").unwrap_or_default(),
+                config.get_var("packTool").unwrap_or_default(),
+                config.get_var("lang").unwrap_or_default(),
+                config.get_var("loader").unwrap_or_default(),
+                config.get_var("css").unwrap_or_default(),
+                format!("{} + {}", combination.dependencies.len() - dev, dev),
+                scripts,
+            ]
+        })
+        .collect();
+    // 按显示宽度对齐，最后一列不补齐
+    let widths: Vec<usize> = (0..header.len())
+        .map(|index| {
+            rows.iter()
+                .chain(std::iter::once(&header))
+                .map(|row| measure_text_width(&row[index]))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let format_row = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(index, (cell, width))| {
+                if index + 1 == row.len() {
+                    cell.clone()
+                } else {
+                    pad_str(cell, *width, Alignment::Left, None).into_owned()
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+    println!("{}", style(format_row(&header)).bold());
+    for row in &rows {
+        println!("{}", format_row(row));
+    }
+}
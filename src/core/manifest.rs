@@ -0,0 +1,377 @@
+use anyhow::{bail, Context, Ok, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::build::{value_name, CopyType, InlineConfig, Templates};
+use super::cli::{CodeLanguage, FrameWork};
+use super::pack::PackTool;
+use super::source::TEMPLATE_MANIFEST_FILE;
+use super::template::{TemplateContext, TemplateValue};
+use crate::utils::error::ElzaError;
+use crate::utils::i18n::translate;
+
+// 模板清单目录，shared 下为可被 extends 复用的片段
+const MANIFEST_DIR: &str = "manifests/";
+const SHARED_MANIFEST_DIR: &str = "manifests/shared/";
+
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DependenciesMod {
+    #[default]
+    Dev,
+    Prod,
+}
+
+#[derive(Clone, Debug)]
+pub struct Dependency {
+    pub name: String,
+    pub version: String,
+    pub mod_type: DependenciesMod,
+    // 声明该依赖的模板清单
+    pub source: String,
+}
+
+// 条件: { "css": "sass", "loader": ["babel", "swc"] }，所有键均满足时成立
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Condition(BTreeMap<String, ConditionValue>);
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum ConditionValue {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl Condition {
+    pub fn matches(&self, config: &InlineConfig) -> Result<bool> {
+        for (key, expected) in &self.0 {
+            let actual = config.get_var(key).with_context(|| {
+                ElzaError::Template(t!("manifest.unknown_condition", key = key))
+            })?;
+            let hit = match expected {
+                ConditionValue::One(value) => value == &actual,
+                ConditionValue::Many(values) => values.contains(&actual),
+            };
+            if !hit {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Layer {
+    pub dir: String,
+    pub copy_type: CopyType,
+    // 外部模板层所在的根目录，为空时使用内置模板
+    #[serde(skip)]
+    pub root: Option<PathBuf>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DependencyRule {
+    pub name: String,
+    pub version: String,
+    #[serde(default, rename = "type")]
+    pub mod_type: DependenciesMod,
+    #[serde(default)]
+    pub when: Condition,
+    // 声明该规则的模板清单，extends 引入的为片段名称
+    #[serde(skip)]
+    pub source: String,
+}
+
+// 满足条件时跳过以 path 开头的文件，copyType 为空时对所有模板层生效
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileRule {
+    pub path: String,
+    pub copy_type: Option<CopyType>,
+    #[serde(default)]
+    pub when: Condition,
+}
+
+// 生成完成后输出的提示，key 为消息目录中的 id，args 替换其中的占位符；
+// 外部模板也可以直接写提示文本，目录中不存在的 id 原样输出
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Tip {
+    Text(String),
+    Message {
+        key: String,
+        #[serde(default)]
+        args: BTreeMap<String, String>,
+    },
+}
+
+impl Tip {
+    pub fn message(&self) -> String {
+        match self {
+            Tip::Text(text) => translate(text, &[]),
+            Tip::Message { key, args } => {
+                let args: Vec<(&str, String)> = args
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.clone()))
+                    .collect();
+                translate(key, &args)
+            }
+        }
+    }
+}
+
+// 模板变量，字符串直接赋值，列表追加到同名变量之后
+#[derive(Clone, Debug, Deserialize)]
+pub struct VariableRule {
+    pub name: String,
+    pub value: TemplateValue,
+    #[serde(default)]
+    pub when: Condition,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateManifest {
+    #[serde(default)]
+    pub name: String,
+    pub frame: Option<FrameWork>,
+    pub pack_tool: Option<PackTool>,
+    #[serde(default)]
+    pub lang: Vec<CodeLanguage>,
+    #[serde(default)]
+    pub extends: Vec<String>,
+    #[serde(default)]
+    pub layers: Vec<Layer>,
+    #[serde(default)]
+    pub package: Map<String, Value>,
+    #[serde(default)]
+    pub dependencies: Vec<DependencyRule>,
+    #[serde(default)]
+    pub files: Vec<FileRule>,
+    #[serde(default)]
+    pub variables: Vec<VariableRule>,
+    #[serde(default)]
+    pub tips: Vec<Tip>,
+    // 外部模板的根目录及来源，内置模板为空
+    #[serde(skip)]
+    pub root: Option<PathBuf>,
+    #[serde(skip)]
+    pub source: Option<String>,
+    // 组成该模板的内置清单文件，含 extends 引入的片段
+    #[serde(skip)]
+    pub manifest_files: Vec<String>,
+}
+
+impl TemplateManifest {
+    // 读取所有模板清单（不含 shared 片段），并展开 extends
+    pub fn all() -> Result<Vec<TemplateManifest>> {
+        let mut manifests = vec![];
+        for path in Templates::iter() {
+            if !path.starts_with(MANIFEST_DIR)
+                || path.starts_with(SHARED_MANIFEST_DIR)
+                || !path.ends_with(".json")
+            {
+                continue;
+            }
+            let mut manifest = load_manifest(&path)?;
+            manifest.manifest_files.push(path.to_string());
+            manifest.resolve_extends(&mut vec![path.to_string()])?;
+            manifests.push(manifest);
+        }
+        Ok(manifests)
+    }
+
+    // 读取外部模板的清单，没有清单文件时整个目录作为一个模板层
+    pub fn load_external(root: &Path, source: &str) -> Result<Self> {
+        let path = root.join(TEMPLATE_MANIFEST_FILE);
+        let mut manifest: TemplateManifest = if path.is_file() {
+            let content = fs::read(&path).with_context(|| {
+                ElzaError::Io(t!("manifest.read_failed", path = format!("{:?}", path)))
+            })?;
+            serde_json::from_slice(&content).with_context(|| {
+                ElzaError::Template(t!("manifest.parse_failed", path = format!("{:?}", path)))
+            })?
+        } else {
+            TemplateManifest::default()
+        };
+        if manifest.name.is_empty() {
+            manifest.name = source.to_string();
+        }
+        if manifest.layers.is_empty() {
+            manifest.layers.push(Layer {
+                dir: ".".to_string(),
+                copy_type: CopyType::Template,
+                root: None,
+            });
+        }
+        // 自身的模板层从外部目录读取，extends 引入的仍为内置模板层
+        for layer in &mut manifest.layers {
+            layer.root = Some(root.to_path_buf());
+        }
+        manifest.resolve_extends(&mut vec![])?;
+        manifest.root = Some(root.to_path_buf());
+        manifest.source = Some(source.to_string());
+        Ok(manifest)
+    }
+
+    // 根据框架、打包工具和语言查找模板清单
+    pub fn find(frame: FrameWork, pack_tool: PackTool, lang: CodeLanguage) -> Result<Self> {
+        Self::all()?
+            .into_iter()
+            .find(|manifest| manifest.matches(frame, pack_tool, lang))
+            .with_context(|| {
+                ElzaError::InvalidCombination(t!(
+                    "manifest.unsupported",
+                    packTool = pack_tool,
+                    frame = frame,
+                    lang = value_name(&lang)
+                ))
+            })
+    }
+
+    pub fn matches(&self, frame: FrameWork, pack_tool: PackTool, lang: CodeLanguage) -> bool {
+        self.frame == Some(frame) && self.pack_tool == Some(pack_tool) && self.lang.contains(&lang)
+    }
+
+    // 当前配置下需要添加的依赖
+    pub fn get_dependencies(&self, config: &InlineConfig) -> Result<Vec<Dependency>> {
+        let mut dependencies = vec![];
+        for rule in &self.dependencies {
+            if rule.when.matches(config)? {
+                dependencies.push(Dependency {
+                    name: rule.name.clone(),
+                    version: rule.version.clone(),
+                    mod_type: rule.mod_type,
+                    source: rule.source.clone(),
+                });
+            }
+        }
+        Ok(dependencies)
+    }
+
+    // 判断是否应该忽略某个文件
+    pub fn should_skip_file(
+        &self,
+        filename: &str,
+        copy_type: CopyType,
+        config: &InlineConfig,
+    ) -> Result<bool> {
+        for rule in &self.files {
+            if filename.starts_with(&rule.path)
+                && rule.copy_type.is_none_or(|t| t == copy_type)
+                && rule.when.matches(config)?
+            {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    // 渲染模板使用的变量：配置项、项目名称及清单中声明的变量
+    pub fn get_context(
+        &self,
+        config: &InlineConfig,
+        project_name: &str,
+    ) -> Result<TemplateContext> {
+        let mut context = config.to_context();
+        context.insert(
+            "name".to_string(),
+            TemplateValue::Str(project_name.to_string()),
+        );
+        for rule in &self.variables {
+            if !rule.when.matches(config)? {
+                continue;
+            }
+            match (context.get_mut(&rule.name), &rule.value) {
+                (Some(TemplateValue::List(values)), TemplateValue::List(extra)) => {
+                    values.extend(extra.iter().cloned());
+                }
+                (Some(TemplateValue::List(_)), TemplateValue::Str(_))
+                | (Some(TemplateValue::Str(_)), TemplateValue::List(_)) => {
+                    bail!(ElzaError::Template(t!(
+                        "manifest.variable_type_mismatch",
+                        name = rule.name
+                    )));
+                }
+                _ => {
+                    context.insert(rule.name.clone(), rule.value.clone());
+                }
+            }
+        }
+        Ok(context)
+    }
+
+    // 合并 extends 片段，自身的模板层、规则排在片段之前
+    fn resolve_extends(&mut self, visited: &mut Vec<String>) -> Result<()> {
+        tag_dependencies(&mut self.dependencies, &self.name);
+        for name in std::mem::take(&mut self.extends) {
+            let path = format!("{}{}.json", MANIFEST_DIR, name);
+            if visited.contains(&path) {
+                bail!(ElzaError::Template(t!(
+                    "manifest.circular_extends",
+                    path = path
+                )));
+            }
+            visited.push(path.clone());
+            let mut base = load_manifest(&path)?;
+            base.resolve_extends(visited)?;
+            visited.pop();
+            tag_dependencies(&mut base.dependencies, &name);
+
+            self.manifest_files.push(path);
+            self.manifest_files.extend(base.manifest_files);
+
+            self.layers.extend(base.layers);
+            self.dependencies.extend(base.dependencies);
+            self.files.extend(base.files);
+            self.variables.extend(base.variables);
+            self.tips.extend(base.tips);
+            let mut package = Value::Object(base.package);
+            merge_json(
+                &mut package,
+                Value::Object(std::mem::take(&mut self.package)),
+            );
+            if let Value::Object(map) = package {
+                self.package = map;
+            }
+        }
+        Ok(())
+    }
+}
+
+// 为尚未标记来源的依赖规则记录所属的模板清单
+fn tag_dependencies(dependencies: &mut [DependencyRule], source: &str) {
+    for rule in dependencies
+        .iter_mut()
+        .filter(|rule| rule.source.is_empty())
+    {
+        rule.source = source.to_string();
+    }
+}
+
+fn load_manifest(path: &str) -> Result<TemplateManifest> {
+    let file = Templates::get(path)
+        .with_context(|| ElzaError::Template(t!("manifest.not_found", path = path)))?;
+    serde_json::from_slice(&file.data)
+        .with_context(|| ElzaError::Template(t!("manifest.parse_failed", path = path)))
+}
+
+// 深度合并 json，对象按键合并，其余类型以 patch 为准
+pub fn merge_json(target: &mut Value, patch: Value) {
+    match (target, patch) {
+        (Value::Object(target_map), Value::Object(patch_map)) => {
+            for (key, value) in patch_map {
+                merge_json(target_map.entry(key).or_insert(Value::Null), value);
+            }
+        }
+        (target, patch) => {
+            *target = patch;
+        }
+    }
+}
@@ -1,5 +1,20 @@
+pub mod add;
 pub mod build;
+pub mod detect;
+pub mod doctor;
+pub mod dry_run;
+pub mod eject;
+pub mod elzarc;
+pub mod generate;
+pub mod list;
 pub mod package_json;
 pub mod cli;
+pub mod manifest;
 pub mod pack;
-pub mod select;
\ No newline at end of file
+pub mod package_manager;
+pub mod resolve;
+pub mod select;
+pub mod source;
+pub mod template;
+pub mod transaction;
+pub mod upgrade;
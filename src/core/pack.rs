@@ -1,14 +1,15 @@
-use anyhow::{Ok, Result};
+use anyhow::{bail, Ok, Result};
 use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
 use std::fmt;
-use std::process::exit;
 
-use super::cli::{DependenciesMod, Dependency};
-use super::select::create_list;
+use super::select::{create_list, ensure_interactive};
+use crate::utils::error::ElzaError;
 use crate::utils::logger;
 
 // 打包工具
-#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PackTool {
     Elza,
     Webpack,
@@ -30,160 +31,25 @@ impl fmt::Display for PackTool {
 }
 
 impl PackTool {
-    pub fn get_dependencies(&self) -> Vec<Dependency> {
+    // 模板依赖要求的最低 Node.js 版本，webpack 模板受限于 webpack-dev-server 5
+    pub fn min_node_version(&self) -> (u64, u64, u64) {
         match self {
-            PackTool::Webpack => vec![
-                Dependency {
-                    name: "webpack-plugin-auto-routes",
-                    version: "1.1.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "webpack-plugin-better-info",
-                    version: "^0.0.4",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "autoprefixer",
-                    version: "^10.4.19",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "copy-webpack-plugin",
-                    version: "^12.0.2",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "cross-env",
-                    version: "^7.0.3",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "css-loader",
-                    version: "6.11.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "css-minimizer-webpack-plugin",
-                    version: "^7.0.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "html-webpack-plugin",
-                    version: "^5.6.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "terser-webpack-plugin",
-                    version: "^5.3.10",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "mini-css-extract-plugin",
-                    version: "^2.9.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "postcss-loader",
-                    version: "^8.1.1",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "style-loader",
-                    version: "^4.0.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "webpack",
-                    version: "^5.91.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "webpack-cli",
-                    version: "^5.1.4",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "webpack-dev-server",
-                    version: "^5.0.4",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "webpack-merge",
-                    version: "^5.10.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-            ],
-            PackTool::Vite => vec![
-                Dependency {
-                    name: "vite",
-                    version: "^5.3.1",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "farm-plugin-auto-routes",
-                    version: "^0.0.5",
-                    mod_type: DependenciesMod::Dev,
-                },
-            ],
-            PackTool::Rsbuild => vec![
-                Dependency {
-                    name: "@rsbuild/core",
-                    version: "^1.1.8",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "webpack-plugin-auto-routes",
-                    version: "1.1.1",
-                    mod_type: DependenciesMod::Dev,
-                },
-            ],
-            PackTool::Farm => vec![
-                Dependency {
-                    name: "@farmfe/cli",
-                    version: "^1.0.2",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "@farmfe/core",
-                    version: "^1.3.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "@farmfe/plugin-react",
-                    version: "^1.2.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "core-js",
-                    version: "^3.36.1",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "react-refresh",
-                    version: "^0.14.0",
-                    mod_type: DependenciesMod::Dev,
-                },
-                Dependency {
-                    name: "farm-plugin-auto-routes",
-                    version: "^0.0.5",
-                    mod_type: DependenciesMod::Dev,
-                },
-            ],
-            PackTool::Elza => vec![Dependency {
-                name: "elza",
-                version: "^0.0.4",
-                mod_type: DependenciesMod::Dev,
-            }],
+            PackTool::Elza => (16, 0, 0),
+            PackTool::Webpack => (18, 12, 0),
+            PackTool::Vite => (18, 0, 0),
+            PackTool::Rsbuild => (16, 10, 0),
+            PackTool::Farm => (16, 15, 1),
         }
     }
 }
 
-pub fn pack_tool_selector(template: Option<PackTool>) -> Result<PackTool> {
+pub fn pack_tool_selector(template: Option<PackTool>, yes: bool) -> Result<PackTool> {
     match template {
         Some(t) => Ok(t),
+        None if yes => Ok(PackTool::Farm),
         None => {
-            logger::pick("请选择打包工具");
+            ensure_interactive("--template")?;
+            logger::pick(&t!("select.pack_tool"));
             let items = vec!["farm", "rsbuild", "vite", "webpack"];
             let selection = create_list(&items, 0)?;
             match selection {
@@ -192,10 +58,10 @@ pub fn pack_tool_selector(template: Option<PackTool>) -> Result<PackTool> {
                 2 => Ok(PackTool::Vite),
                 3 => Ok(PackTool::Webpack),
                 // 4 => Ok(PackTool::Elza),
-                _ => {
-                    logger::error(&format!("暂不支持: {}", &items[selection]));
-                    exit(1);
-                }
+                _ => bail!(ElzaError::InvalidCombination(t!(
+                    "select.unsupported",
+                    item = items[selection]
+                ))),
             }
         }
     }
@@ -1,10 +1,10 @@
-use super::build::ProjectType;
-use super::cli::DependenciesMod;
+use super::manifest::{ merge_json, DependenciesMod };
 use crate::logger;
-use anyhow::{ Ok, Result };
-use serde_json::{ Map, Value };
+use crate::utils::error::ElzaError;
+use anyhow::{ Context, Ok, Result };
+use serde_json::{ json, Map, Value };
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 pub struct PackageJson {
     project_dir: PathBuf,
@@ -14,76 +14,34 @@ pub struct PackageJson {
 #[derive(Debug, Clone)]
 pub struct PackageBasicInfo {
     pub name: String,
-    pub project_type: ProjectType,
+    pub fields: Map<String, Value>,
 }
 
 impl PackageJson {
-    pub fn new(project_dir: &PathBuf) -> Result<Self> {
-        let mut path = project_dir.clone();
-        path.push("package.json");
-        let content = fs::read_to_string(&path).unwrap();
-        let json = serde_json::from_str(&content).unwrap();
+    pub fn new(project_dir: &Path) -> Result<Self> {
+        let path = project_dir.join("package.json");
+        let content = fs
+            ::read_to_string(&path)
+            .with_context(|| ElzaError::Io(t!("package_json.read_failed", path = format!("{:?}", path))))?;
+        Self::parse(project_dir, &content)
+    }
+
+    // 从内存中的内容创建，用于尚未写入磁盘的项目
+    pub fn parse(project_dir: &Path, content: &str) -> Result<Self> {
+        let path = project_dir.join("package.json");
+        let json = serde_json
+            ::from_str(content)
+            .with_context(|| ElzaError::Template(t!("package_json.parse_failed", path = format!("{:?}", path))))?;
         Ok(Self {
-            project_dir: project_dir.clone(),
+            project_dir: project_dir.to_path_buf(),
             json,
         })
     }
 
     pub fn update_basic(&mut self, basic_info: PackageBasicInfo) -> Result<()> {
         self.json["name"] = Value::String(basic_info.name);
-        match basic_info.project_type {
-            ProjectType::WebpackReactJs => {
-                set_scripts(
-                    &mut self.json,
-                    "cross-env NODE_ENV=development webpack-dev-server --config ./scripts/webpack.dev.js",
-                    "cross-env NODE_ENV=production webpack --config ./scripts/webpack.prod.js",
-                    None
-                );
-            }
-            ProjectType::WebpackReactTs => {
-                set_scripts(
-                    &mut self.json,
-                    "cross-env NODE_ENV=development webpack-dev-server --config ./scripts/webpack.dev.ts",
-                    "cross-env NODE_ENV=production webpack --config ./scripts/webpack.prod.ts",
-                    None
-                );
-            }
-            ProjectType::ViteReactJs => {
-                self.json["type"] = Value::String("module".to_string());
-                set_scripts(&mut self.json, "vite", "vite build", Some("vite preview"));
-            }
-            ProjectType::ViteReactTs => {
-                self.json["type"] = Value::String("module".to_string());
-                set_scripts(&mut self.json, "vite", "vite build", Some("vite preview"));
-            }
-            ProjectType::RspackReactJs => {
-                set_scripts(
-                    &mut self.json,
-                    "rsbuild dev",
-                    "rsbuild build",
-                    Some("rsbuild preview")
-                );
-            }
-            ProjectType::RspackReactTs => {
-                set_scripts(
-                    &mut self.json,
-                    "rsbuild dev",
-                    "rsbuild build",
-                    Some("rsbuild preview")
-                );
-            }
-            ProjectType::FarmReact => {
-                set_scripts(&mut self.json, "farm start", "farm build", Some("farm preview"));
-            }
-            ProjectType::ElzaReactJs => {
-                self.json["type"] = Value::String("module".to_string());
-                set_scripts(&mut self.json, "elza dev", "elza build", None);
-            }
-            ProjectType::ElzaReactTs => {
-                self.json["type"] = Value::String("module".to_string());
-                set_scripts(&mut self.json, "elza dev", "elza build", None);
-            }
-        }
+        // 合并模板清单中声明的字段，如 type、scripts
+        merge_json(&mut self.json, Value::Object(basic_info.fields));
         Ok(())
     }
 
@@ -93,7 +51,11 @@ impl PackageJson {
         dependency_version: &str,
         mode: DependenciesMod
     ) -> Result<()> {
-        logger::event(&format!("开始添加依赖: {} => {}", dependency_name, dependency_version));
+        logger::record(
+            "dependency_added",
+            &t!("package_json.add_dependency", name = dependency_name, version = dependency_version),
+            json!({ "name": dependency_name, "version": dependency_version, "dependencyType": mode })
+        );
         let dev_or_prod = match mode {
             DependenciesMod::Dev => "devDependencies",
             DependenciesMod::Prod => "dependencies",
@@ -103,22 +65,65 @@ impl PackageJson {
             .and_then(|obj| obj.get_mut(dev_or_prod))
             .and_then(|value| value.as_object_mut())
             .ok_or_else(|| {
-                anyhow::anyhow!("'{}' field not found or is not an object", dev_or_prod)
+                anyhow::Error::new(
+                    ElzaError::Template(t!("package_json.missing_field", field = dev_or_prod))
+                )
             })?;
         deps.insert(dependency_name.to_string(), Value::String(dependency_version.to_string()));
 
         Ok(())
     }
 
+    pub fn remove_dependency(&mut self, dependency_name: &str) {
+        for dev_or_prod in ["dependencies", "devDependencies"] {
+            let removed = self.json
+                .get_mut(dev_or_prod)
+                .and_then(|value| value.as_object_mut())
+                .and_then(|deps| deps.shift_remove(dependency_name));
+            if removed.is_some() {
+                logger::debug(&t!("package_json.remove_dependency", name = dependency_name));
+            }
+        }
+    }
+
+    pub fn has_dependency(&self, dependency_name: &str) -> bool {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .any(|dev_or_prod| self.json[dev_or_prod].get(dependency_name).is_some())
+    }
+
+    pub fn dependency_version(&self, dependency_name: &str) -> Option<&str> {
+        ["dependencies", "devDependencies"]
+            .iter()
+            .find_map(|dev_or_prod| self.json[dev_or_prod][dependency_name].as_str())
+    }
+
+    pub fn name(&self) -> &str {
+        self.json["name"].as_str().unwrap_or_default()
+    }
+
+    pub fn script(&self, script_name: &str) -> Option<&str> {
+        self.json["scripts"][script_name].as_str()
+    }
+
     pub fn sort(&mut self) {
         sort_json(&mut self.json);
     }
 
+    pub fn json(&self) -> &Value {
+        &self.json
+    }
+
+    pub fn content(&self) -> String {
+        serde_json::to_string_pretty(&self.json).unwrap()
+    }
+
     pub fn write(&self) -> Result<()> {
         let mut path = self.project_dir.clone();
         path.push("package.json");
-        let content = serde_json::to_string_pretty(&self.json).unwrap();
-        fs::write(&path, content).unwrap();
+        fs
+            ::write(&path, self.content())
+            .with_context(|| ElzaError::Io(t!("package_json.write_failed", path = format!("{:?}", path))))?;
 
         Ok(())
     }
@@ -158,11 +163,3 @@ fn sort_map(json_value: &mut Value) {
         *map = sorted_map;
     }
 }
-
-fn set_scripts(json_value: &mut Value, start: &str, build: &str, preview: Option<&str>) {
-    json_value["scripts"]["start"] = Value::String(start.to_string());
-    json_value["scripts"]["build"] = Value::String(build.to_string());
-    if let Some(preview_script) = preview {
-        json_value["scripts"]["preview"] = Value::String(preview_script.to_string());
-    }
-}
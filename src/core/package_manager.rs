@@ -0,0 +1,135 @@
+use anyhow::{bail, Context, Ok, Result};
+use clap::ValueEnum;
+use std::env;
+use std::fmt;
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use crate::utils::error::ElzaError;
+use crate::utils::logger;
+
+// 包管理器
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum PackageManager {
+    Npm,
+    Pnpm,
+    Yarn,
+    Bun,
+}
+
+// 锁文件与包管理器的对应关系，按优先级排列
+const LOCKFILES: [(&str, PackageManager); 5] = [
+    ("pnpm-lock.yaml", PackageManager::Pnpm),
+    ("yarn.lock", PackageManager::Yarn),
+    ("bun.lockb", PackageManager::Bun),
+    ("bun.lock", PackageManager::Bun),
+    ("package-lock.json", PackageManager::Npm),
+];
+
+impl fmt::Display for PackageManager {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PackageManager::Npm => write!(f, "npm"),
+            PackageManager::Pnpm => write!(f, "pnpm"),
+            PackageManager::Yarn => write!(f, "yarn"),
+            PackageManager::Bun => write!(f, "bun"),
+        }
+    }
+}
+
+impl PackageManager {
+    // 优先使用 --pm 参数，其次是调用方的 user agent，再次是上级目录中的锁文件，默认 npm
+    pub fn detect(pm: Option<PackageManager>) -> PackageManager {
+        if let Some(pm) = pm {
+            return pm;
+        }
+        if let Some(pm) = env::var("npm_config_user_agent")
+            .ok()
+            .and_then(|agent| Self::from_user_agent(&agent))
+        {
+            return pm;
+        }
+        env::current_dir()
+            .ok()
+            .and_then(|dir| Self::from_lockfile(&dir))
+            .unwrap_or(PackageManager::Npm)
+    }
+
+    // user agent 形如 "pnpm/9.1.0 npm/? node/v20.12.2 darwin arm64"
+    fn from_user_agent(agent: &str) -> Option<PackageManager> {
+        let name = agent.split('/').next()?;
+        PackageManager::from_str(name, true).ok()
+    }
+
+    fn from_lockfile(dir: &Path) -> Option<PackageManager> {
+        dir.ancestors().find_map(|dir| {
+            LOCKFILES
+                .iter()
+                .find(|(lockfile, _)| dir.join(lockfile).is_file())
+                .map(|(_, pm)| *pm)
+        })
+    }
+
+    // 安装模板依赖所需的最低版本，npm 7 起才会自动安装 peerDependencies
+    pub fn min_version(&self) -> (u64, u64, u64) {
+        match self {
+            PackageManager::Npm => (7, 0, 0),
+            PackageManager::Pnpm => (8, 0, 0),
+            PackageManager::Yarn => (1, 22, 0),
+            PackageManager::Bun => (1, 0, 0),
+        }
+    }
+
+    // 安装依赖的命令
+    pub fn install_command(&self) -> String {
+        format!("{} install", self)
+    }
+
+    // 执行 package.json 中脚本的命令
+    pub fn run_command(&self, script: &str) -> String {
+        match self {
+            PackageManager::Npm | PackageManager::Bun => format!("{} run {}", self, script),
+            PackageManager::Pnpm | PackageManager::Yarn => format!("{} {}", self, script),
+        }
+    }
+
+    // 可执行文件名，windows 下 npm、pnpm 和 yarn 均为 .cmd 脚本
+    pub fn program(&self) -> String {
+        if cfg!(windows) && *self != PackageManager::Bun {
+            format!("{}.cmd", self)
+        } else {
+            self.to_string()
+        }
+    }
+
+    // 在项目目录中安装依赖，输出直接打印到终端，json 日志格式下转到 stderr
+    pub fn install(&self, project_dir: &Path) -> Result<()> {
+        logger::event(&t!("install.start", command = self.install_command()));
+        logger::debug(&t!(
+            "common.run_command",
+            command = format!("{} install", self.program())
+        ));
+        let status = Command::new(self.program())
+            .arg("install")
+            .current_dir(project_dir)
+            .stdout(install_stdout())
+            .status()
+            .with_context(|| ElzaError::Environment(t!("install.spawn_failed", pm = self)))?;
+        if !status.success() {
+            bail!(ElzaError::Environment(t!(
+                "install.failed",
+                command = self.install_command()
+            )));
+        }
+        logger::info(&t!("install.done"));
+        Ok(())
+    }
+}
+
+fn install_stdout() -> Stdio {
+    if logger::is_json() {
+        std::io::stderr().into()
+    } else {
+        Stdio::inherit()
+    }
+}
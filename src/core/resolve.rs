@@ -0,0 +1,179 @@
+use reqwest::Client;
+use std::time::Duration;
+use tokio::task::JoinSet;
+
+use super::manifest::Dependency;
+use crate::utils::{logger, utils};
+
+// 单个依赖查询的超时时间
+const RESOLVE_TIMEOUT: Duration = Duration::from_secs(5);
+
+// 并发查询镜像源中的最新版本，查询失败或超时的依赖保留预设版本
+pub async fn resolve_latest_versions(
+    dependencies: Vec<Dependency>,
+    registry: &str,
+) -> Vec<Dependency> {
+    resolve_with_timeout(dependencies, registry, RESOLVE_TIMEOUT).await
+}
+
+async fn resolve_with_timeout(
+    dependencies: Vec<Dependency>,
+    registry: &str,
+    timeout: Duration,
+) -> Vec<Dependency> {
+    logger::event(&t!("resolve.start", registry = registry));
+    let client = match Client::builder().timeout(timeout).build() {
+        Ok(client) => client,
+        Err(e) => {
+            logger::warning(&t!("resolve.client_failed", error = e));
+            return dependencies;
+        }
+    };
+
+    let mut tasks = JoinSet::new();
+    for (index, dep) in dependencies.iter().enumerate() {
+        let client = client.clone();
+        let registry = registry.to_string();
+        let name = dep.name.clone();
+        tasks.spawn(async move {
+            let result = utils::fetch_latest_version(&client, &registry, &name).await;
+            (index, result)
+        });
+    }
+
+    let mut resolved = dependencies;
+    while let Some(joined) = tasks.join_next().await {
+        let Ok((index, result)) = joined else {
+            continue;
+        };
+        let dep = &mut resolved[index];
+        match result {
+            Ok(latest) if !latest.is_empty() => {
+                dep.version = format!("{}{}", range_prefix(&dep.version), latest);
+            }
+            Ok(_) => logger::warning(&t!(
+                "resolve.not_found",
+                name = dep.name,
+                version = dep.version
+            )),
+            Err(e) => logger::warning(&t!(
+                "resolve.failed",
+                name = dep.name,
+                version = dep.version,
+                error = e
+            )),
+        }
+    }
+    logger::info(&t!("resolve.done"));
+    resolved
+}
+
+// 沿用预设版本的范围前缀，精确锁定的版本仍保持精确
+fn range_prefix(version: &str) -> &str {
+    if version.starts_with('^') || version.starts_with('~') {
+        &version[..1]
+    } else {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::manifest::DependenciesMod;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::{TcpListener, TcpStream};
+
+    // 本地模拟镜像源，/slow 永不响应，未知包返回 404
+    async fn mock_registry() -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                tokio::spawn(respond(stream));
+            }
+        });
+        format!("http://{}/", addr)
+    }
+
+    async fn respond(mut stream: TcpStream) {
+        let mut buf = vec![0; 4096];
+        let mut len = 0;
+        while !buf[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+            match stream.read(&mut buf[len..]).await {
+                Ok(0) | Err(_) => return,
+                Ok(n) => len += n,
+            }
+        }
+        let request = String::from_utf8_lossy(&buf[..len]);
+        let path = request.split_whitespace().nth(1).unwrap_or_default();
+        let latest = match path {
+            "/react" => "18.3.1",
+            "/typescript" => "5.6.3",
+            "/vite" => "5.4.10",
+            "/@types%2Freact" => "18.3.12",
+            "/slow" => {
+                tokio::time::sleep(Duration::from_secs(60)).await;
+                return;
+            }
+            _ => {
+                let response =
+                    "HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n";
+                let _ = stream.write_all(response.as_bytes()).await;
+                return;
+            }
+        };
+        let body = format!(r#"{{"dist-tags":{{"latest":"{}"}}}}"#, latest);
+        let response = format!(
+            "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        let _ = stream.write_all(response.as_bytes()).await;
+    }
+
+    fn dependency(name: &str, version: &str) -> Dependency {
+        Dependency {
+            name: name.to_string(),
+            version: version.to_string(),
+            mod_type: DependenciesMod::Dev,
+            source: "test".to_string(),
+        }
+    }
+
+    async fn resolve(dependencies: &[(&str, &str)]) -> Vec<String> {
+        let registry = mock_registry().await;
+        let dependencies = dependencies
+            .iter()
+            .map(|(name, version)| dependency(name, version))
+            .collect();
+        resolve_with_timeout(dependencies, &registry, Duration::from_millis(500))
+            .await
+            .into_iter()
+            .map(|dep| dep.version)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn keeps_range_prefix() {
+        let versions = resolve(&[
+            ("react", "^18.2.0"),
+            ("typescript", "~5.0.0"),
+            ("vite", "5.0.0"),
+        ])
+        .await;
+        assert_eq!(versions, ["^18.3.1", "~5.6.3", "5.4.10"]);
+    }
+
+    #[tokio::test]
+    async fn falls_back_on_not_found_and_timeout() {
+        let versions = resolve(&[("missing", "^1.0.0"), ("slow", "~2.0.0")]).await;
+        assert_eq!(versions, ["^1.0.0", "~2.0.0"]);
+    }
+
+    #[tokio::test]
+    async fn escapes_scoped_names() {
+        let versions = resolve(&[("@types/react", "^18.0.0")]).await;
+        assert_eq!(versions, ["^18.3.12"]);
+    }
+}
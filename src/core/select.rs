@@ -1,6 +1,9 @@
-use anyhow::{ Result, Context };
+use std::io::IsTerminal;
+use anyhow::{ bail, Result };
+use std::io::ErrorKind;
 use console::Style;
 use dialoguer::{ console::{ style, Term }, theme::ColorfulTheme, Select };
+use crate::utils::error::ElzaError;
 
 pub fn create_list(items: &[&str], default: usize) -> Result<usize> {
     Select::with_theme(
@@ -10,9 +13,25 @@ pub fn create_list(items: &[&str], default: usize) -> Result<usize> {
             ..ColorfulTheme::default()
         })
     )
-        .items(&items)
+        .items(items)
         .default(default)
         .interact_on_opt(&Term::stderr())
-        .context("选择项失败")?
-        .ok_or_else(|| anyhow::anyhow!("未选择任何项"))
+        .map_err(|dialoguer::Error::IO(e)| {
+            // 交互中按下 Ctrl-C 时读取会被中断
+            if e.kind() == ErrorKind::Interrupted {
+                anyhow::Error::new(ElzaError::PromptCancelled)
+            } else {
+                anyhow::Error::new(e).context(ElzaError::Io(t!("select.failed")))
+            }
+        })?
+        .ok_or(ElzaError::PromptCancelled)
+        .map_err(anyhow::Error::new)
+}
+
+// 非交互环境（如 CI）下无法弹出选择列表，缺少的选项需要通过参数指定
+pub fn ensure_interactive(flag: &str) -> Result<()> {
+    if !std::io::stdin().is_terminal() {
+        bail!(ElzaError::InvalidCombination(t!("select.not_interactive", flag = flag)));
+    }
+    Ok(())
 }
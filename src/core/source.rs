@@ -0,0 +1,272 @@
+use anyhow::{bail, Context, Ok, Result};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::utils::error::ElzaError;
+use crate::utils::logger;
+
+use super::manifest::TemplateManifest;
+use super::package_manager::PackageManager;
+
+// 外部模板的清单文件，位于模板根目录，格式与内置模板清单一致
+pub const TEMPLATE_MANIFEST_FILE: &str = "elza.template.json";
+
+// 遍历外部模板时忽略的目录
+const IGNORED_DIRS: [&str; 2] = [".git", "node_modules"];
+
+// 通过 --from 指定的外部模板：本地目录、git 仓库或 npm 包
+pub struct ExternalTemplate {
+    // 记录到 .elzarc.json 中的模板来源，本地路径统一为绝对路径
+    pub spec: String,
+    pub root: PathBuf,
+    // 下载到临时目录的模板，使用完毕后删除
+    temp_dir: Option<PathBuf>,
+}
+
+impl ExternalTemplate {
+    pub fn fetch(spec: &str) -> Result<Self> {
+        let path = Path::new(spec);
+        if path.is_dir() {
+            let root = fs::canonicalize(path)
+                .with_context(|| ElzaError::Io(t!("source.read_dir_failed", path = spec)))?;
+            logger::info(&t!("source.local", path = root.display()));
+            return Ok(Self {
+                spec: root.to_string_lossy().to_string(),
+                root,
+                temp_dir: None,
+            });
+        }
+
+        let temp_dir = create_temp_dir()?;
+        // 先持有临时目录，获取失败时同样会被清理
+        let mut template = Self {
+            spec: spec.to_string(),
+            root: temp_dir.clone(),
+            temp_dir: Some(temp_dir.clone()),
+        };
+        if is_git_url(spec) {
+            template.root = clone_repository(spec, &temp_dir)?;
+        } else if path.is_file() {
+            let tarball = fs::canonicalize(path)
+                .with_context(|| ElzaError::Io(t!("source.read_file_failed", path = spec)))?;
+            template.spec = tarball.to_string_lossy().to_string();
+            template.root = extract_tarball(&tarball, &temp_dir)?;
+        } else {
+            let tarball = pack_npm_package(spec, &temp_dir)?;
+            template.root = extract_tarball(&tarball, &temp_dir)?;
+        }
+        Ok(template)
+    }
+
+    pub fn manifest(&self) -> Result<TemplateManifest> {
+        TemplateManifest::load_external(&self.root, &self.spec)
+    }
+}
+
+impl Drop for ExternalTemplate {
+    fn drop(&mut self) {
+        if let Some(temp_dir) = &self.temp_dir {
+            let _ = fs::remove_dir_all(temp_dir);
+        }
+    }
+}
+
+// 列出目录下的全部文件，返回以 / 分隔的相对路径
+pub fn list_files(dir: &Path) -> Result<Vec<String>> {
+    let mut files = vec![];
+    if dir.is_dir() {
+        collect_files(dir, dir, &mut files)?;
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn collect_files(root: &Path, dir: &Path, files: &mut Vec<String>) -> Result<()> {
+    for entry in fs::read_dir(dir)
+        .with_context(|| ElzaError::Io(t!("file.read_dir_failed", path = format!("{:?}", dir))))?
+    {
+        let path = entry?.path();
+        if path.is_dir() {
+            let ignored = path
+                .file_name()
+                .is_some_and(|name| IGNORED_DIRS.iter().any(|ignored| name == *ignored));
+            if !ignored {
+                collect_files(root, &path, files)?;
+            }
+            continue;
+        }
+        let relative = path.strip_prefix(root)?;
+        files.push(
+            relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/"),
+        );
+    }
+    Ok(())
+}
+
+fn create_temp_dir() -> Result<PathBuf> {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let dir = env::temp_dir().join(format!(
+        "elza-cli-template-{}-{}",
+        std::process::id(),
+        nanos
+    ));
+    fs::create_dir_all(&dir).with_context(|| {
+        ElzaError::Io(t!("file.create_temp_failed", path = format!("{:?}", dir)))
+    })?;
+    Ok(dir)
+}
+
+// http(s) 地址中除 .tgz 等压缩包交给 npm pack 下载外，带有仓库路径的均视为 git 仓库
+fn is_git_url(spec: &str) -> bool {
+    let url = spec.split('#').next().unwrap_or(spec);
+    if let Some(rest) = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+    {
+        let is_tarball = [".tgz", ".tar.gz"].iter().any(|ext| url.ends_with(ext));
+        return !is_tarball
+            && rest.split_once('/').is_some_and(|(host, path)| {
+                !host.is_empty() && !path.trim_matches('/').is_empty()
+            });
+    }
+    ["git@", "git+", "git://", "ssh://", "file://"]
+        .iter()
+        .any(|prefix| url.starts_with(prefix))
+        || url.ends_with(".git")
+}
+
+// 浅克隆仓库，url#ref 可指定分支或标签
+fn clone_repository(spec: &str, temp_dir: &Path) -> Result<PathBuf> {
+    let (url, reference) = match spec.split_once('#') {
+        Some((url, reference)) => (url, Some(reference)),
+        None => (spec, None),
+    };
+    let url = url.strip_prefix("git+").unwrap_or(url);
+    let target = temp_dir.join("repository");
+    logger::event(&t!("source.clone", spec = spec));
+    let mut command = Command::new("git");
+    command.args(["clone", "--depth", "1"]);
+    if let Some(reference) = reference {
+        command.args(["--branch", reference]);
+    }
+    logger::debug(&t!(
+        "common.run_command",
+        command = format!("git clone --depth 1 {} {}", url, target.display())
+    ));
+    let status = command
+        .arg(url)
+        .arg(&target)
+        .stdout(Stdio::null())
+        .status()
+        .context(ElzaError::Git(t!("source.git_missing")))?;
+    if !status.success() {
+        bail!(ElzaError::Git(t!("source.clone_failed", spec = spec)));
+    }
+    Ok(target)
+}
+
+// 通过 npm pack 下载 npm 包，沿用用户的镜像源及认证配置
+fn pack_npm_package(spec: &str, temp_dir: &Path) -> Result<PathBuf> {
+    logger::event(&t!("source.download", spec = spec));
+    logger::debug(&t!(
+        "common.run_command",
+        command = format!(
+            "npm pack {} --pack-destination {}",
+            spec,
+            temp_dir.display()
+        )
+    ));
+    let output = Command::new(PackageManager::Npm.program())
+        .args(["pack", spec, "--silent", "--pack-destination"])
+        .arg(temp_dir)
+        .stderr(Stdio::inherit())
+        .output()
+        .with_context(|| ElzaError::Environment(t!("source.npm_missing")))?;
+    if !output.status.success() {
+        bail!(ElzaError::Registry(t!(
+            "source.download_failed",
+            spec = spec
+        )));
+    }
+    let filename = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .last()
+        .map(|line| line.trim().to_string())
+        .unwrap_or_default();
+    if filename.is_empty() {
+        bail!(ElzaError::Registry(t!(
+            "source.download_failed",
+            spec = spec
+        )));
+    }
+    Ok(temp_dir.join(filename))
+}
+
+// npm 包解压后位于 package 目录下，其他压缩包只有一个顶层目录时以该目录为模板根目录
+fn extract_tarball(tarball: &Path, temp_dir: &Path) -> Result<PathBuf> {
+    let target = temp_dir.join("extract");
+    fs::create_dir_all(&target)?;
+    let status = Command::new("tar")
+        .arg("-xzf")
+        .arg(tarball)
+        .arg("-C")
+        .arg(&target)
+        .status()
+        .with_context(|| ElzaError::Environment(t!("source.tar_missing")))?;
+    if !status.success() {
+        bail!(ElzaError::Io(t!(
+            "source.extract_failed",
+            path = format!("{:?}", tarball)
+        )));
+    }
+    let entries: Vec<PathBuf> = fs::read_dir(&target)?
+        .map(|entry| entry.map(|e| e.path()))
+        .collect::<std::io::Result<_>>()?;
+    Ok(match &entries[..] {
+        [dir] if dir.is_dir() => dir.clone(),
+        _ => target,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_git_urls() {
+        for spec in [
+            "https://github.com/org/repo",
+            "https://gitlab.example.com/group/sub/repo/#v1.0.0",
+            "http://git.internal/org/repo.git",
+            "git+https://github.com/org/repo",
+            "git@github.com:org/repo.git",
+            "ssh://git@github.com/org/repo",
+            "file:///tmp/repo",
+        ] {
+            assert!(is_git_url(spec), "{spec}");
+        }
+    }
+
+    #[test]
+    fn leaves_packages_and_tarballs_to_npm() {
+        for spec in [
+            "elza-template-react",
+            "@scope/elza-template@1.2.0",
+            "https://registry.npmjs.org/pkg/-/pkg-1.0.0.tgz",
+            "https://example.com/",
+            "https://example.com",
+        ] {
+            assert!(!is_git_url(spec), "{spec}");
+        }
+    }
+}
@@ -0,0 +1,446 @@
+use anyhow::{anyhow, bail, Ok, Result};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+use crate::utils::error::ElzaError;
+
+// 模板语法：
+//   {%= name %}                     输出变量
+//   {%= plugins | join(", ") %}     以分隔符拼接列表变量
+//   {% if css == sass %} ... {% elif css != none %} ... {% else %} ... {% end %}
+//   {% for item in imports %} ... {% end %}
+//   {% raw %} ... {% endraw %}      其中的内容原样输出，用于保留 Nunjucks 等同样使用 {% %} 的文件
+// 条件左侧为变量名，右侧为字面量（可加引号），列表变量的 == 表示包含，
+// 支持 not、and、or；单独占一行的控制标签会连同所在行一起移除
+const OPEN: &str = "{%";
+const CLOSE: &str = "%}";
+const RAW: &str = "raw";
+const END_RAW: &str = "endraw";
+// 旧版模板的占位符，渲染结果中出现时说明模板尚未迁移
+const LEGACY_PLACEHOLDER: &str = "`placeholder:";
+
+#[derive(Clone, Debug, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum TemplateValue {
+    Str(String),
+    List(Vec<String>),
+}
+
+pub type TemplateContext = BTreeMap<String, TemplateValue>;
+
+enum Token {
+    Text(String),
+    // raw 块内的文本
+    Raw(String),
+    Output(String),
+    Control(String),
+}
+
+enum Node {
+    Text(String),
+    Raw(String),
+    Output(String),
+    If(Vec<(String, Vec<Node>)>, Vec<Node>),
+    For(String, String, Vec<Node>),
+}
+
+// 渲染模板，raw 块之外的渲染结果中不允许出现旧版占位符
+pub fn render(source: &str, context: &TemplateContext) -> Result<String> {
+    let tokens = tokenize(source)?;
+    let mut tokens = tokens.into_iter().peekable();
+    let (nodes, end) = parse(&mut tokens)?;
+    if let Some(tag) = end {
+        bail!(t!("template.extra_tag", tag = tag));
+    }
+    let mut scopes = vec![context.clone()];
+    let mut output = String::new();
+    render_nodes(&nodes, &mut scopes, &mut output)?;
+    Ok(output)
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut rest = source;
+    // 当前位置是否处于行首（之前只有空白）
+    let mut at_line_start = true;
+    // 是否处于 raw 块内，块内除 endraw 外的标签均作为文本输出
+    let mut raw = false;
+    while let Some(start) = rest.find(OPEN) {
+        let mut text = &rest[..start];
+        let after_open = &rest[start + OPEN.len()..];
+        let Some(end) = after_open.find(CLOSE) else {
+            if raw {
+                break;
+            }
+            bail!(t!(
+                "template.unclosed_tag",
+                tag = first_line(&rest[start..])
+            ));
+        };
+        let inner = after_open[..end].trim();
+        let mut remaining = &after_open[end + CLOSE.len()..];
+
+        if raw && inner != END_RAW {
+            let tag_end = start + OPEN.len() + end + CLOSE.len();
+            push_raw(&mut tokens, &rest[..tag_end]);
+            at_line_start = false;
+        } else if let Some(expr) = inner.strip_prefix('=') {
+            push_text(&mut tokens, text);
+            tokens.push(Token::Output(expr.trim().to_string()));
+            at_line_start = false;
+        } else {
+            let line_start = text.rfind('\n').map(|i| i + 1);
+            let indent = &text[line_start.unwrap_or(0)..];
+            let standalone_before =
+                indent.trim().is_empty() && (line_start.is_some() || at_line_start);
+            let line_end = remaining.find('\n');
+            let trailing = &remaining[..line_end.unwrap_or(remaining.len())];
+            if standalone_before && trailing.trim().is_empty() {
+                text = &text[..text.len() - indent.len()];
+                remaining = &remaining[line_end.map_or(remaining.len(), |i| i + 1)..];
+                at_line_start = true;
+            } else {
+                at_line_start = false;
+            }
+            if raw {
+                push_raw(&mut tokens, text);
+            } else {
+                push_text(&mut tokens, text);
+            }
+            match inner {
+                RAW => raw = true,
+                END_RAW if raw => raw = false,
+                _ => tokens.push(Token::Control(inner.to_string())),
+            }
+        }
+        rest = remaining;
+    }
+    if raw {
+        bail!(t!("template.raw_missing_end"));
+    }
+    push_text(&mut tokens, rest);
+    Ok(tokens)
+}
+
+fn push_raw(tokens: &mut Vec<Token>, text: &str) {
+    if !text.is_empty() {
+        tokens.push(Token::Raw(text.to_string()));
+    }
+}
+
+fn push_text(tokens: &mut Vec<Token>, text: &str) {
+    if !text.is_empty() {
+        tokens.push(Token::Text(text.to_string()));
+    }
+}
+
+fn first_line(text: &str) -> &str {
+    text.lines().next().unwrap_or(text)
+}
+
+// 解析节点直至遇到 elif/else/end，返回遇到的结束标签
+fn parse<I: Iterator<Item = Token>>(
+    tokens: &mut std::iter::Peekable<I>,
+) -> Result<(Vec<Node>, Option<String>)> {
+    let mut nodes = vec![];
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Text(text) => nodes.push(Node::Text(text)),
+            Token::Raw(text) => nodes.push(Node::Raw(text)),
+            Token::Output(expr) => nodes.push(Node::Output(expr)),
+            Token::Control(tag) => {
+                let (keyword, args) = split_keyword(&tag);
+                match keyword {
+                    "if" => nodes.push(parse_if(args.to_string(), tokens)?),
+                    "for" => {
+                        let (item, list) = args
+                            .split_once(" in ")
+                            .map(|(item, list)| (item.trim(), list.trim()))
+                            .filter(|(item, list)| !item.is_empty() && !list.is_empty())
+                            .ok_or_else(|| anyhow!(t!("template.invalid_for", tag = tag)))?;
+                        let (body, end) = parse(tokens)?;
+                        if end.as_deref() != Some("end") {
+                            bail!(t!("template.for_missing_end"));
+                        }
+                        nodes.push(Node::For(item.to_string(), list.to_string(), body));
+                    }
+                    "elif" | "else" | "end" => return Ok((nodes, Some(tag))),
+                    _ => bail!(t!("template.unknown_tag", tag = tag)),
+                }
+            }
+        }
+    }
+    Ok((nodes, None))
+}
+
+fn parse_if<I: Iterator<Item = Token>>(
+    condition: String,
+    tokens: &mut std::iter::Peekable<I>,
+) -> Result<Node> {
+    let mut branches = vec![];
+    let mut condition = condition;
+    loop {
+        let (body, end) = parse(tokens)?;
+        branches.push((condition, body));
+        let end = end.ok_or_else(|| anyhow!(t!("template.if_missing_end")))?;
+        let (keyword, args) = split_keyword(&end);
+        match keyword {
+            "elif" => condition = args.to_string(),
+            "else" => {
+                let (else_body, end) = parse(tokens)?;
+                if end.as_deref() != Some("end") {
+                    bail!(t!("template.else_missing_end"));
+                }
+                return Ok(Node::If(branches, else_body));
+            }
+            _ => return Ok(Node::If(branches, vec![])),
+        }
+    }
+}
+
+fn split_keyword(tag: &str) -> (&str, &str) {
+    match tag.split_once(char::is_whitespace) {
+        Some((keyword, args)) => (keyword, args.trim()),
+        None => (tag, ""),
+    }
+}
+
+fn render_nodes(
+    nodes: &[Node],
+    scopes: &mut Vec<TemplateContext>,
+    output: &mut String,
+) -> Result<()> {
+    for node in nodes {
+        match node {
+            Node::Text(text) => output.push_str(check_legacy(text)?),
+            Node::Raw(text) => output.push_str(text),
+            Node::Output(expr) => output.push_str(check_legacy(&eval_output(expr, scopes)?)?),
+            Node::If(branches, else_body) => {
+                let mut matched = false;
+                for (condition, body) in branches {
+                    if eval_condition(condition, scopes)? {
+                        render_nodes(body, scopes, output)?;
+                        matched = true;
+                        break;
+                    }
+                }
+                if !matched {
+                    render_nodes(else_body, scopes, output)?;
+                }
+            }
+            Node::For(item, list, body) => {
+                let values = match lookup(list, scopes)? {
+                    TemplateValue::List(values) => values.clone(),
+                    TemplateValue::Str(_) => bail!(t!("template.not_list", name = list)),
+                };
+                for value in values {
+                    let mut scope = TemplateContext::new();
+                    scope.insert(item.clone(), TemplateValue::Str(value));
+                    scopes.push(scope);
+                    let result = render_nodes(body, scopes, output);
+                    scopes.pop();
+                    result?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_legacy(text: &str) -> Result<&str> {
+    if let Some(index) = text.find(LEGACY_PLACEHOLDER) {
+        let placeholder = text[index..].split_whitespace().next().unwrap_or_default();
+        bail!(ElzaError::Template(t!(
+            "template.legacy_placeholder",
+            placeholder = placeholder
+        )));
+    }
+    Ok(text)
+}
+
+fn lookup<'a>(name: &str, scopes: &'a [TemplateContext]) -> Result<&'a TemplateValue> {
+    scopes
+        .iter()
+        .rev()
+        .find_map(|scope| scope.get(name))
+        .ok_or_else(|| anyhow!(t!("template.undefined_variable", name = name)))
+}
+
+fn eval_output(expr: &str, scopes: &[TemplateContext]) -> Result<String> {
+    let (name, filter) = match expr.split_once('|') {
+        Some((name, filter)) => (name.trim(), Some(filter.trim())),
+        None => (expr, None),
+    };
+    match (lookup(name, scopes)?, filter) {
+        (TemplateValue::Str(value), None) => Ok(value.clone()),
+        (TemplateValue::List(values), Some(filter)) => {
+            let separator = filter
+                .strip_prefix("join(")
+                .and_then(|args| args.strip_suffix(')'))
+                .map(unquote)
+                .ok_or_else(|| anyhow!(t!("template.unknown_filter", filter = filter)))?;
+            Ok(values.join(separator))
+        }
+        (TemplateValue::List(_), None) => bail!(t!("template.list_needs_join", name = name)),
+        (TemplateValue::Str(_), Some(filter)) => {
+            bail!(t!(
+                "template.filter_unsupported",
+                name = name,
+                filter = filter
+            ))
+        }
+    }
+}
+
+fn eval_condition(condition: &str, scopes: &[TemplateContext]) -> Result<bool> {
+    if condition.is_empty() {
+        bail!(t!("template.empty_condition"));
+    }
+    for any in condition.split(" or ") {
+        let mut all = true;
+        for atom in any.split(" and ") {
+            if !eval_atom(atom.trim(), scopes)? {
+                all = false;
+                break;
+            }
+        }
+        if all {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+fn eval_atom(atom: &str, scopes: &[TemplateContext]) -> Result<bool> {
+    if let Some(inner) = atom.strip_prefix("not ") {
+        return Ok(!eval_atom(inner.trim(), scopes)?);
+    }
+    for (operator, equal) in [("!=", false), ("==", true)] {
+        if let Some((name, literal)) = atom.split_once(operator) {
+            let matched = match lookup(name.trim(), scopes)? {
+                TemplateValue::Str(value) => value == unquote(literal.trim()),
+                TemplateValue::List(values) => values.iter().any(|v| v == unquote(literal.trim())),
+            };
+            return Ok(matched == equal);
+        }
+    }
+    Ok(match lookup(atom, scopes)? {
+        TemplateValue::Str(value) => !value.is_empty(),
+        TemplateValue::List(values) => !values.is_empty(),
+    })
+}
+
+fn unquote(literal: &str) -> &str {
+    let literal = literal.trim();
+    for quote in ['"', '\''] {
+        if let Some(inner) = literal
+            .strip_prefix(quote)
+            .and_then(|l| l.strip_suffix(quote))
+        {
+            return inner;
+        }
+    }
+    literal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(vars: &[(&str, TemplateValue)]) -> TemplateContext {
+        vars.iter()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect()
+    }
+
+    fn text(value: &str) -> TemplateValue {
+        TemplateValue::Str(value.to_string())
+    }
+
+    fn list(values: &[&str]) -> TemplateValue {
+        TemplateValue::List(values.iter().map(|v| v.to_string()).collect())
+    }
+
+    #[test]
+    fn if_elif_else() {
+        let source = "{% if css == sass %}sass{% elif css != none %}other{% else %}none{% end %}";
+        for (css, expected) in [("sass", "sass"), ("less", "other"), ("none", "none")] {
+            let ctx = context(&[("css", text(css))]);
+            assert_eq!(render(source, &ctx).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn for_loop_and_join_filter() {
+        let ctx = context(&[("plugins", list(&["react()", "less()"]))]);
+        let source = "{% for p in plugins %}[{%= p %}]{% end %} {%= plugins | join(\", \") %}";
+        assert_eq!(
+            render(source, &ctx).unwrap(),
+            "[react()][less()] react(), less()"
+        );
+    }
+
+    #[test]
+    fn standalone_tag_lines_are_removed() {
+        let ctx = context(&[("css", text("less")), ("imports", list(&["a", "b"]))]);
+        let source = "start\n  {% if css == less %}\nimport less;\n  {% end %}\n{% for i in imports %}\n{%= i %}\n{% end %}\nend\n";
+        assert_eq!(
+            render(source, &ctx).unwrap(),
+            "start\nimport less;\na\nb\nend\n"
+        );
+    }
+
+    #[test]
+    fn nested_blocks() {
+        let ctx = context(&[("lang", text("ts")), ("loader", list(&["babel", "swc"]))]);
+        let source = "{% if lang == ts %}{% for l in loader %}{% if l == swc %}<{%= l %}>{% else %}{%= l %},{% end %}{% end %}{% end %}";
+        assert_eq!(render(source, &ctx).unwrap(), "babel,<swc>");
+    }
+
+    #[test]
+    fn raw_blocks_and_percent_braces_are_kept() {
+        let ctx = context(&[("name", text("demo"))]);
+        let source = "{% raw %}\n{% block body %}{{ name }}{% endblock %}\n{% endraw %}\n.a { width: 50%}\n{%= name %}";
+        assert_eq!(
+            render(source, &ctx).unwrap(),
+            "{% block body %}{{ name }}{% endblock %}\n.a { width: 50%}\ndemo"
+        );
+    }
+
+    #[test]
+    fn legacy_placeholders_fail_outside_raw_blocks() {
+        let ctx = context(&[("name", text("demo"))]);
+        let err = render("plugins: [`placeholder:0`],", &ctx).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<ElzaError>(),
+            Some(ElzaError::Template(_))
+        ));
+        let source = "{% raw %}`placeholder:0`{% endraw %} {%= name %}";
+        assert_eq!(render(source, &ctx).unwrap(), "`placeholder:0` demo");
+    }
+
+    #[test]
+    fn unknown_variable_is_an_error() {
+        let err = render("{%= missing %}", &TemplateContext::new()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            t!("template.undefined_variable", name = "missing")
+        );
+    }
+
+    #[test]
+    fn unclosed_blocks_are_errors() {
+        let ctx = context(&[("css", text("sass")), ("items", list(&["a"]))]);
+        let cases = [
+            ("{% if css == sass %}x", t!("template.if_missing_end")),
+            ("{% for i in items %}x", t!("template.for_missing_end")),
+            ("{% if css %}x{% else %}y", t!("template.else_missing_end")),
+            ("{% raw %}x", t!("template.raw_missing_end")),
+            ("{%= css", t!("template.unclosed_tag", tag = "{%= css")),
+        ];
+        for (source, expected) in cases {
+            let err = render(source, &ctx).unwrap_err();
+            assert_eq!(err.to_string(), expected, "source: {source}");
+        }
+    }
+}
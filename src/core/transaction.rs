@@ -0,0 +1,114 @@
+use anyhow::{bail, Context, Ok, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::utils::error::ElzaError;
+use crate::utils::logger;
+
+// 尚未完成的临时项目目录，进程被中断时统一清理
+static PENDING_DIRS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+
+// 项目先生成到目标目录旁的临时目录，全部完成后再重命名为目标目录，
+// 未提交时（出错、panic 或被中断）删除临时目录
+pub struct ProjectTransaction {
+    temp_dir: PathBuf,
+    target: PathBuf,
+    committed: bool,
+}
+
+impl ProjectTransaction {
+    pub fn begin(target: &Path) -> Result<Self> {
+        let name = target
+            .file_name()
+            .with_context(|| t!("create.invalid_path", path = format!("{:?}", target)))?
+            .to_string_lossy();
+        let parent = match target.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+        // 与目标目录位于同一文件系统，保证重命名为原子操作
+        let temp_dir = parent.join(format!(".{}.elza-{}", name, std::process::id()));
+        if temp_dir.exists() {
+            fs::remove_dir_all(&temp_dir).with_context(|| {
+                ElzaError::Io(t!(
+                    "file.clean_temp_failed",
+                    path = format!("{:?}", temp_dir)
+                ))
+            })?;
+        }
+        fs::create_dir_all(&temp_dir).with_context(|| {
+            ElzaError::Io(t!(
+                "file.create_temp_failed",
+                path = format!("{:?}", temp_dir)
+            ))
+        })?;
+        lock_pending().push(temp_dir.clone());
+        Ok(Self {
+            temp_dir,
+            target: target.to_path_buf(),
+            committed: false,
+        })
+    }
+
+    // 生成过程中写入的目录
+    pub fn dir(&self) -> &Path {
+        &self.temp_dir
+    }
+
+    pub fn commit(mut self) -> Result<()> {
+        if self.target.exists() {
+            bail!(ElzaError::Io(t!(
+                "create.exists",
+                path = format!("{:?}", self.target)
+            )));
+        }
+        fs::rename(&self.temp_dir, &self.target).with_context(|| {
+            ElzaError::Io(t!("create.dir_failed", path = format!("{:?}", self.target)))
+        })?;
+        self.committed = true;
+        lock_pending().retain(|dir| *dir != self.temp_dir);
+        Ok(())
+    }
+}
+
+impl Drop for ProjectTransaction {
+    fn drop(&mut self) {
+        if self.committed {
+            return;
+        }
+        lock_pending().retain(|dir| *dir != self.temp_dir);
+        if fs::remove_dir_all(&self.temp_dir).is_ok() {
+            logger::warning(&t!("create.rolled_back"));
+        }
+    }
+}
+
+// 删除所有未完成的临时目录，用于直接退出进程前
+pub fn rollback() {
+    for dir in lock_pending().drain(..) {
+        let _ = fs::remove_dir_all(dir);
+    }
+}
+
+// 捕获 Ctrl-C，清理未完成的项目后退出
+pub fn listen_interrupt() -> Result<()> {
+    ctrlc::set_handler(|| {
+        rollback();
+        // 交互选择时光标被隐藏，退出前恢复
+        let term = console::Term::stderr();
+        if term.is_term() {
+            let _ = term.show_cursor();
+        }
+        eprintln!();
+        logger::warning(&t!("common.cancelled"));
+        std::process::exit(130);
+    })
+    .with_context(|| t!("common.interrupt_handler_failed"))
+}
+
+fn lock_pending() -> std::sync::MutexGuard<'static, Vec<PathBuf>> {
+    PENDING_DIRS
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
@@ -0,0 +1,348 @@
+use anyhow::{bail, Ok, Result};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+use crate::utils::error::ElzaError;
+use crate::utils::logger;
+
+use super::build::{render_project, write_project_file};
+use super::elzarc::{hash_files, read_base, template_revision, write_base, ElzaRc, ELZARC_FILE};
+use super::manifest::{Dependency, TemplateManifest};
+use super::package_json::PackageJson;
+use super::package_manager::PackageManager;
+use super::source::ExternalTemplate;
+
+// 依赖单独处理，不参与文件合并
+const PACKAGE_JSON: &str = "package.json";
+
+// 以记录的配置重新渲染当前版本的模板，与用户文件三方合并
+pub fn upgrade_project(project_dir: &Path) -> Result<()> {
+    let Some(mut rc) = ElzaRc::read(project_dir)? else {
+        bail!(ElzaError::Io(t!("upgrade.no_rc", file = ELZARC_FILE)));
+    };
+    // 外部模板重新从来源获取
+    let external = rc
+        .source
+        .as_deref()
+        .map(ExternalTemplate::fetch)
+        .transpose()?;
+    let config = rc.config;
+    let manifest = match &external {
+        Some(external) => external.manifest()?,
+        None => TemplateManifest::find(config.frame, config.pack_tool, config.lang)?,
+    };
+    let revision = template_revision(&manifest)?;
+    if rc.template_revision == revision {
+        logger::info(&t!("upgrade.up_to_date"));
+        return Ok(());
+    }
+    logger::info(&t!(
+        "upgrade.start",
+        from = rc.version,
+        to = env!("CARGO_PKG_VERSION")
+    ));
+
+    let mut pj = PackageJson::new(project_dir)?;
+    let context = manifest.get_context(&config, pj.name())?;
+    let new_files = render_project(&manifest, config, &context)?;
+    let base_files = read_base(project_dir)?;
+    if base_files.is_empty() {
+        logger::warning(&t!("upgrade.no_base"));
+    }
+
+    let mut conflicts = vec![];
+    let paths: BTreeSet<&String> = base_files
+        .keys()
+        .chain(new_files.keys())
+        .filter(|path| *path != PACKAGE_JSON)
+        .collect();
+    for path in paths {
+        if merge_file(
+            project_dir,
+            &rc,
+            path,
+            base_files.get(path),
+            new_files.get(path),
+        )? {
+            conflicts.push(path.clone());
+        }
+    }
+
+    upgrade_dependencies(&mut pj, &mut rc, manifest.get_dependencies(&config)?)?;
+    pj.sort();
+    pj.write()?;
+
+    rc.version = env!("CARGO_PKG_VERSION").to_string();
+    rc.template_revision = revision;
+    rc.files = hash_files(&new_files);
+    rc.record_file(project_dir, PACKAGE_JSON)?;
+    rc.write(project_dir)?;
+    write_base(project_dir, &new_files)?;
+
+    for path in &conflicts {
+        logger::warning(&t!("upgrade.conflict", path = path));
+    }
+    // 已写入冲突标记，以非零退出码提醒脚本及 CI
+    if !conflicts.is_empty() {
+        bail!(ElzaError::Conflict(t!(
+            "upgrade.conflicts",
+            count = conflicts.len()
+        )));
+    }
+    logger::ready(&t!("upgrade.done"));
+    logger::full_info(&PackageManager::detect(None).install_command());
+    Ok(())
+}
+
+// 合并单个文件，返回是否存在冲突
+fn merge_file(
+    project_dir: &Path,
+    rc: &ElzaRc,
+    path: &str,
+    base: Option<&Vec<u8>>,
+    theirs: Option<&Vec<u8>>,
+) -> Result<bool> {
+    // 模板未变化，保留用户文件
+    if base == theirs {
+        return Ok(false);
+    }
+    let file_path = project_dir.join(path);
+    let ours = fs::read(&file_path).ok();
+    if ours.as_ref() == theirs {
+        return Ok(false);
+    }
+    let ours = match (ours, base) {
+        // 用户删除的文件保持删除
+        (None, Some(_)) => return Ok(false),
+        // 模板新增的文件
+        (None, None) => Vec::new(),
+        (Some(ours), _) => ours,
+    };
+    // 没有合并基准时根据生成记录判断是否被修改过
+    let unmodified = match base {
+        Some(base) => ours == *base,
+        None => ours.is_empty() || rc.is_unmodified(path, &ours),
+    };
+    if unmodified {
+        match theirs {
+            Some(data) => write_project_file(project_dir, path, data)?,
+            None => {
+                logger::debug(&t!("file.delete", path = path));
+                fs::remove_file(&file_path)?;
+            }
+        }
+        return Ok(false);
+    }
+
+    let Some(theirs) = theirs else {
+        logger::warning(&t!("upgrade.kept_removed", path = path));
+        return Ok(false);
+    };
+    let texts = [base.map(Vec::as_slice).unwrap_or_default(), &ours, theirs]
+        .map(|data| std::str::from_utf8(data).ok());
+    let [Some(base), Some(ours), Some(theirs)] = texts else {
+        logger::warning(&t!("upgrade.binary", path = path));
+        return Ok(true);
+    };
+    match diffy::merge(base, ours, theirs) {
+        std::result::Result::Ok(merged) => {
+            logger::debug(&t!("upgrade.merge", path = path));
+            fs::write(&file_path, merged)?;
+            Ok(false)
+        }
+        Err(with_markers) => {
+            fs::write(&file_path, with_markers)?;
+            Ok(true)
+        }
+    }
+}
+
+// 未被用户改动过的预设依赖随模板升级，用户修改或删除过的依赖保持不变
+fn upgrade_dependencies(
+    pj: &mut PackageJson,
+    rc: &mut ElzaRc,
+    dependencies: Vec<Dependency>,
+) -> Result<()> {
+    let mut recorded = BTreeMap::new();
+    for dep in dependencies {
+        let current = pj.dependency_version(&dep.name);
+        let upgradable = match (current, rc.dependencies.get(&dep.name)) {
+            (None, None) => true,
+            (Some(current), Some(old)) => current == old && *old != dep.version,
+            _ => false,
+        };
+        if upgradable {
+            pj.update_dependencies(&dep.name, &dep.version, dep.mod_type)?;
+        }
+        recorded.insert(dep.name, dep.version);
+    }
+    for (name, old) in &rc.dependencies {
+        if !recorded.contains_key(name) && pj.dependency_version(name) == Some(old.as_str()) {
+            pj.remove_dependency(name);
+        }
+    }
+    rc.dependencies = recorded;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::build::InlineConfig;
+    use crate::core::cli::{
+        CodeLanguage, CssPreset, FrameWork, JsLoader, StateManagement, UIDesign, UtilityCss,
+    };
+    use crate::core::manifest::DependenciesMod;
+    use crate::core::pack::PackTool;
+    use crate::utils::error::exit_code;
+    use crate::utils::test_utils::TempDir;
+
+    fn config() -> InlineConfig {
+        InlineConfig {
+            frame: FrameWork::React,
+            pack_tool: PackTool::Webpack,
+            lang: CodeLanguage::Ts,
+            loader: JsLoader::Babel,
+            ui: UIDesign::None,
+            state: StateManagement::None,
+            css: CssPreset::None,
+            utility: UtilityCss::None,
+        }
+    }
+
+    fn rc(files: &[(&str, &str)], dependencies: &[(&str, &str)]) -> ElzaRc {
+        let files: BTreeMap<String, Vec<u8>> = files
+            .iter()
+            .map(|(path, content)| (path.to_string(), content.as_bytes().to_vec()))
+            .collect();
+        ElzaRc {
+            version: "0.0.0".to_string(),
+            config: config(),
+            template_revision: "stale".to_string(),
+            source: None,
+            files: hash_files(&files),
+            dependencies: dependencies
+                .iter()
+                .map(|(name, version)| (name.to_string(), version.to_string()))
+                .collect(),
+        }
+    }
+
+    fn merge(dir: &TempDir, base: Option<&str>, theirs: Option<&str>) -> bool {
+        let base = base.map(|text| text.as_bytes().to_vec());
+        let theirs = theirs.map(|text| text.as_bytes().to_vec());
+        merge_file(
+            dir.path(),
+            &rc(&[], &[]),
+            "file.txt",
+            base.as_ref(),
+            theirs.as_ref(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn replaces_unmodified_file() {
+        let dir = TempDir::new();
+        dir.write("file.txt", "one\ntwo\n");
+        assert!(!merge(&dir, Some("one\ntwo\n"), Some("one\nTWO\n")));
+        assert_eq!(dir.read("file.txt").unwrap(), "one\nTWO\n");
+    }
+
+    #[test]
+    fn merges_user_edit() {
+        let dir = TempDir::new();
+        dir.write("file.txt", "one\ntwo\nthree\nfour\nfive\nsix\n");
+        assert!(!merge(
+            &dir,
+            Some("one\ntwo\nthree\nfour\nfive\n"),
+            Some("one\nTWO\nthree\nfour\nfive\n")
+        ));
+        assert_eq!(
+            dir.read("file.txt").unwrap(),
+            "one\nTWO\nthree\nfour\nfive\nsix\n"
+        );
+    }
+
+    #[test]
+    fn writes_markers_on_conflict() {
+        let dir = TempDir::new();
+        dir.write("file.txt", "one\nmine\nthree\n");
+        assert!(merge(
+            &dir,
+            Some("one\ntwo\nthree\n"),
+            Some("one\nTWO\nthree\n")
+        ));
+        let content = dir.read("file.txt").unwrap();
+        assert!(content.contains("<<<<<<<"));
+        assert!(content.contains("mine"));
+        assert!(content.contains("TWO"));
+    }
+
+    #[test]
+    fn keeps_file_deleted_by_user() {
+        let dir = TempDir::new();
+        assert!(!merge(&dir, Some("one\n"), Some("ONE\n")));
+        assert!(dir.read("file.txt").is_none());
+    }
+
+    #[test]
+    fn keeps_dependency_changed_by_user() {
+        let dir = TempDir::new();
+        dir.write(
+            PACKAGE_JSON,
+            r#"{ "devDependencies": { "react": "^17.0.0", "webpack": "^5.0.0", "old": "^1.0.0" } }"#,
+        );
+        let mut pj = PackageJson::new(dir.path()).unwrap();
+        let mut rc = rc(
+            &[],
+            &[
+                ("react", "^18.0.0"),
+                ("webpack", "^5.0.0"),
+                ("old", "^1.0.0"),
+            ],
+        );
+        let dependencies = [("react", "^19.0.0"), ("webpack", "^5.1.0")]
+            .map(|(name, version)| Dependency {
+                name: name.to_string(),
+                version: version.to_string(),
+                mod_type: DependenciesMod::Dev,
+                source: String::new(),
+            })
+            .to_vec();
+        upgrade_dependencies(&mut pj, &mut rc, dependencies).unwrap();
+
+        assert_eq!(pj.dependency_version("react"), Some("^17.0.0"));
+        assert_eq!(pj.dependency_version("webpack"), Some("^5.1.0"));
+        assert_eq!(pj.dependency_version("old"), None);
+        assert_eq!(rc.dependencies["react"], "^19.0.0");
+    }
+
+    #[test]
+    fn exits_with_conflict_code() {
+        let dir = TempDir::new();
+        let config = config();
+        let manifest = TemplateManifest::find(config.frame, config.pack_tool, config.lang).unwrap();
+        let context = manifest.get_context(&config, "demo").unwrap();
+        let mut files = render_project(&manifest, config, &context).unwrap();
+        for (path, data) in &files {
+            dir.write(path, data);
+        }
+        PackageJson::new(dir.path()).unwrap().write().unwrap();
+        let mut rc = rc(&[], &[]);
+        rc.files = hash_files(&files);
+        rc.write(dir.path()).unwrap();
+        // 基准与用户文件的首行各不相同，与模板三方冲突
+        let path = "src/index.tsx";
+        let content = String::from_utf8(files[path].clone()).unwrap();
+        let rest = content.split_once('\n').unwrap().1;
+        dir.write(path, format!("// mine\n{}", rest));
+        files.insert(path.to_string(), format!("// base\n{}", rest).into_bytes());
+        write_base(dir.path(), &files).unwrap();
+
+        let error = upgrade_project(dir.path()).unwrap_err();
+        assert_eq!(exit_code(&error), 7);
+        assert!(dir.read(path).unwrap().contains("<<<<<<<"));
+    }
+}
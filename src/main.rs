@@ -1,6 +1,8 @@
+#[macro_use]
 mod utils;
 mod core;
-use anyhow::{ Ok, Result };
+use std::path::PathBuf;
+use anyhow::{ Context, Ok, Result };
 use console::style;
 use tokio::runtime::Runtime;
 use lazy_static::lazy_static;
@@ -11,157 +13,721 @@ use clap::{
     Subcommand,
     ValueEnum,
 };
-use crate::utils::logger;
+use crate::utils::error::exit_code;
+use crate::utils::i18n::{ self, LOCALE_ENV };
+use crate::utils::error::ElzaError;
+use crate::utils::logger::{ self, ColorChoice, LogFormat, LoggerOptions, LEVEL_ENV };
+use crate::core::add::{ add_features, AddOptions };
+use crate::core::doctor::doctor;
+use crate::core::eject::{ eject_layer, eject_project };
+use crate::core::transaction::listen_interrupt;
+use crate::core::generate::{ generate, GenerateKind };
+use crate::core::list::list_templates;
+use crate::core::upgrade::upgrade_project;
 use crate::core::pack::PackTool;
-use crate::core::cli::{ create_project, FrameWork };
+use crate::core::package_manager::PackageManager;
+use crate::core::cli::{ create_project, select_config, CodeLanguage, CreateOptions, CssPreset, FrameWork, JsLoader, StateManagement, UIDesign, UtilityCss };
 
 lazy_static! {
     static ref CUSTOM_HELP: String = format!(
-        "{} {} {}\n\n{}\n    {}           项目名称\n\n{}\n    {}           创建一个新项目\n\n{}\n    {}   项目模板 [可选值: {}]\n    {}      项目框架 [可选值: {}]\n    {}    版本信息\n    {}       输出帮助信息",
+        "{} {} {}\n\n{}\n    {}           {}\n\n{}\n    {}           {}\n    {}              {}\n    {}          {}\n    {}         {}\n    {}         {}\n    {}           {}\n    {}             {}\n\n{}\n    {}   {} {}\n    {}      {} {}\n    {}       {} {}\n    {}     {} {}\n    {}        {} {}\n    {}    {} {}\n    {}      {} {}\n    {}         {} {}\n    {}         {} {}\n    {} {}\n    {}     {}\n    {}   {}\n    {}       {}\n    {}    {}\n    {}        {}\n    {}     {} {}\n    {} {} {}\n    {}      {}\n    {}    {}\n    {}   {}\n    {}      {} {}\n    {}    {}\n    {}       {}\n\n{}\n    {} {}\n    {}         {}\n    {}          {}",
         style("Usage").yellow(),
         style("elza-cli create").cyan(),
         style("[Options] [Name]").blue(),
         style("Arguments:").yellow(),
         style("[Name]").cyan(),
+        t!("help.arg_name"),
         style("Command:").yellow(),
         style("create").cyan(),
+        t!("help.cmd_create"),
+        style("add").cyan(),
+        t!("help.cmd_add"),
+        style("upgrade").cyan(),
+        t!("help.cmd_upgrade"),
+        style("generate").cyan(),
+        t!("help.cmd_generate"),
+        style("template").cyan(),
+        t!("help.cmd_template"),
+        style("doctor").cyan(),
+        t!("help.cmd_doctor"),
+        style("list").cyan(),
+        t!("help.cmd_list"),
         style("Options:").yellow(),
         style("-t, --template").cyan(),
-        get_possible_template_values(),
+        t!("help.opt_template"),
+        get_possible_values::<PackTool>(),
         style("-f, --frame").cyan(),
-        get_possible_frame_values(),
+        t!("help.opt_frame"),
+        get_possible_values::<FrameWork>(),
+        style("-l, --lang").cyan(),
+        t!("help.opt_lang"),
+        get_possible_values::<CodeLanguage>(),
+        style("    --loader").cyan(),
+        t!("help.opt_loader"),
+        get_possible_values::<JsLoader>(),
+        style("-c, --css").cyan(),
+        t!("help.opt_css"),
+        get_possible_values::<CssPreset>(),
+        style("-u, --utility").cyan(),
+        t!("help.opt_utility"),
+        get_possible_values::<UtilityCss>(),
+        style("-s, --state").cyan(),
+        t!("help.opt_state"),
+        get_possible_values::<StateManagement>(),
+        style("    --ui").cyan(),
+        t!("help.opt_ui"),
+        get_possible_values::<UIDesign>(),
+        style("    --pm").cyan(),
+        t!("help.opt_pm"),
+        get_possible_values::<PackageManager>(),
+        style("    --no-install").cyan(),
+        t!("help.opt_no_install"),
+        style("    --latest").cyan(),
+        t!("help.opt_latest"),
+        style("    --registry").cyan(),
+        t!("help.opt_registry"),
+        style("    --from").cyan(),
+        t!("help.opt_from"),
+        style("    --dry-run").cyan(),
+        t!("help.opt_dry_run"),
+        style("-y, --yes").cyan(),
+        t!("help.opt_yes"),
+        style("    --locale").cyan(),
+        t!("help.opt_locale"),
+        t!("help.possible_values", values = "zh-CN,en-US"),
+        style("    --log-format").cyan(),
+        t!("help.opt_log_format"),
+        get_possible_values::<LogFormat>(),
+        style("-q, --quiet").cyan(),
+        t!("help.opt_quiet"),
+        style("-v, --verbose").cyan(),
+        t!("help.opt_verbose"),
+        style("    --log-file").cyan(),
+        t!("help.opt_log_file"),
+        style("    --color").cyan(),
+        t!("help.opt_color"),
+        get_possible_values::<ColorChoice>(),
         style("-V, --version").cyan(),
-        style("-h, --help").cyan()
+        t!("help.opt_version"),
+        style("-h, --help").cyan(),
+        t!("help.opt_help"),
+        style("Environment:").yellow(),
+        style("ELZA_TEMPLATE_DIR").cyan(),
+        t!("help.env_template_dir"),
+        style(LOCALE_ENV).cyan(),
+        t!("help.env_lang"),
+        style(LEVEL_ENV).cyan(),
+        t!("help.env_log")
+    );
+    static ref UPGRADE_HELP: String = format!(
+        "{} {}\n\n{}\n\n{}\n    {}       {}",
+        style("Usage").yellow(),
+        style("elza-cli upgrade").cyan(),
+        t!("help.upgrade_about"),
+        style("Options:").yellow(),
+        style("-h, --help").cyan(),
+        t!("help.opt_help")
+    );
+    static ref DOCTOR_HELP: String = format!(
+        "{} {}\n\n{}\n\n{}\n    {}       {}",
+        style("Usage").yellow(),
+        style("elza-cli doctor").cyan(),
+        t!("help.doctor_about"),
+        style("Options:").yellow(),
+        style("-h, --help").cyan(),
+        t!("help.opt_help")
+    );
+    static ref LIST_HELP: String = format!(
+        "{} {} {}\n\n{}\n\n{}\n    {}       {}\n    {}       {}",
+        style("Usage").yellow(),
+        style("elza-cli list").cyan(),
+        style("[Options]").blue(),
+        t!("help.list_about"),
+        style("Options:").yellow(),
+        style("    --json").cyan(),
+        t!("help.list_json"),
+        style("-h, --help").cyan(),
+        t!("help.opt_help")
+    );
+    static ref GENERATE_HELP: String = format!(
+        "{} {} {}\n\n{}\n    {}           {} {}\n    {}           {}\n\n{}\n    {}       {}",
+        style("Usage").yellow(),
+        style("elza-cli generate").cyan(),
+        style("<Type> <Name>").blue(),
+        style("Arguments:").yellow(),
+        style("<Type>").cyan(),
+        t!("help.generate_type"),
+        get_possible_values::<GenerateKind>(),
+        style("<Name>").cyan(),
+        t!("help.generate_name"),
+        style("Options:").yellow(),
+        style("-h, --help").cyan(),
+        t!("help.opt_help")
+    );
+    static ref TEMPLATE_HELP: String = format!(
+        "{} {} {}\n\n{}\n\n{}\n    {}          {}\n\n{}\n    {}        {}\n    {}   {} {}\n    {}      {} {}\n    {}       {} {}\n    {}     {} {}\n    {}        {} {}\n    {}    {} {}\n    {}      {} {}\n    {}         {} {}\n    {}        {}\n    {}       {}",
+        style("Usage").yellow(),
+        style("elza-cli template eject").cyan(),
+        style("[Options] [Layer]").blue(),
+        t!("help.template_about"),
+        style("Arguments:").yellow(),
+        style("[Layer]").cyan(),
+        t!("help.template_layer"),
+        style("Options:").yellow(),
+        style("-o, --out").cyan(),
+        t!("help.template_out"),
+        style("-t, --template").cyan(),
+        t!("help.opt_template"),
+        get_possible_values::<PackTool>(),
+        style("-f, --frame").cyan(),
+        t!("help.opt_frame"),
+        get_possible_values::<FrameWork>(),
+        style("-l, --lang").cyan(),
+        t!("help.opt_lang"),
+        get_possible_values::<CodeLanguage>(),
+        style("    --loader").cyan(),
+        t!("help.opt_loader"),
+        get_possible_values::<JsLoader>(),
+        style("-c, --css").cyan(),
+        t!("help.opt_css"),
+        get_possible_values::<CssPreset>(),
+        style("-u, --utility").cyan(),
+        t!("help.opt_utility"),
+        get_possible_values::<UtilityCss>(),
+        style("-s, --state").cyan(),
+        t!("help.opt_state"),
+        get_possible_values::<StateManagement>(),
+        style("    --ui").cyan(),
+        t!("help.opt_ui"),
+        get_possible_values::<UIDesign>(),
+        style("-y, --yes").cyan(),
+        t!("help.opt_yes"),
+        style("-h, --help").cyan(),
+        t!("help.opt_help")
+    );
+    static ref ADD_HELP: String = format!(
+        "{} {} {}\n\n{}\n    {}     {} {}\n    {}        {} {}\n    {}    {} {}\n    {}      {} {}\n    {}         {} {}\n    {}      {}\n    {}       {}",
+        style("Usage").yellow(),
+        style("elza-cli add").cyan(),
+        style("[Options]").blue(),
+        style("Options:").yellow(),
+        style("    --loader").cyan(),
+        t!("help.add_loader"),
+        get_possible_values::<JsLoader>(),
+        style("-c, --css").cyan(),
+        t!("help.opt_css"),
+        get_possible_values::<CssPreset>(),
+        style("-u, --utility").cyan(),
+        t!("help.opt_utility"),
+        get_possible_values::<UtilityCss>(),
+        style("-s, --state").cyan(),
+        t!("help.opt_state"),
+        get_possible_values::<StateManagement>(),
+        style("    --ui").cyan(),
+        t!("help.opt_ui"),
+        get_possible_values::<UIDesign>(),
+        style("    --force").cyan(),
+        t!("help.add_force"),
+        style("-h, --help").cyan(),
+        t!("help.opt_help")
     );
 }
 
 #[derive(Parser, Debug)]
-#[command(name = "elza-cli", author, version, about, args_conflicts_with_subcommands = true)]
+#[command(name = "elza-cli", author, version, about)]
 #[command(override_help = CUSTOM_HELP.as_str())]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    // 输出语言，帮助信息在解析参数前生成，实际由 flag_value 预先读取
+    #[arg(help = t!("help.opt_locale"), long = "locale", value_name = "LANG", global = true)]
+    locale: Option<String>,
+
+    #[arg(
+        help = t!("help.opt_log_format"),
+        long = "log-format",
+        value_name = "FORMAT",
+        value_parser = EnumValueParser::<LogFormat>::new(),
+        global = true,
+        ignore_case = true
+    )]
+    log_format: Option<LogFormat>,
+
+    #[arg(help = t!("help.opt_quiet"), short = 'q', long = "quiet", global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    #[arg(help = t!("help.opt_verbose"), short = 'v', long = "verbose", global = true)]
+    verbose: bool,
+
+    #[arg(help = t!("help.opt_log_file"), long = "log-file", value_name = "PATH", value_hint = ValueHint::FilePath, global = true)]
+    log_file: Option<PathBuf>,
+
+    // 同 --locale，由 flag_value 在解析参数前预先读取
+    #[arg(
+        help = t!("help.opt_color"),
+        long = "color",
+        value_name = "WHEN",
+        value_parser = EnumValueParser::<ColorChoice>::new(),
+        global = true,
+        ignore_case = true
+    )]
+    color: Option<ColorChoice>,
 }
 
 #[derive(Subcommand, Debug)]
 enum Commands {
     // 创建一个新项目
     #[command(override_help = CUSTOM_HELP.as_str(), version)] Create {
-        #[arg(help = "项目名称", value_hint = ValueHint::DirPath, ignore_case = true)]
+        #[arg(help = t!("help.arg_name"), value_hint = ValueHint::DirPath, ignore_case = true)]
         name: Option<String>,
 
         #[arg(
-            help = "项目模板",
+            help = t!("help.opt_template"),
             short = 't',
             long = "template",
-            value_name = "模板",
+            value_name = "TEMPLATE",
             value_parser = EnumValueParser::<PackTool>::new(),
             ignore_case = true
         )]
         template: Option<PackTool>,
         
         #[arg(
-            help = "项目框架",
+            help = t!("help.opt_frame"),
             short = 'f',
             long = "frame",
-            value_name = "框架",
+            value_name = "FRAME",
             value_parser = EnumValueParser::<FrameWork>::new(),
             ignore_case = true
         )]
         frame_work: Option<FrameWork>,
+
+        #[arg(
+            help = t!("help.opt_lang"),
+            short = 'l',
+            long = "lang",
+            value_name = "LANG",
+            value_parser = EnumValueParser::<CodeLanguage>::new(),
+            ignore_case = true
+        )]
+        lang: Option<CodeLanguage>,
+
+        #[arg(
+            help = t!("help.opt_loader"),
+            long = "loader",
+            value_name = "loader",
+            value_parser = EnumValueParser::<JsLoader>::new(),
+            ignore_case = true
+        )]
+        loader: Option<JsLoader>,
+
+        #[arg(
+            help = t!("help.opt_css"),
+            short = 'c',
+            long = "css",
+            value_name = "CSS",
+            value_parser = EnumValueParser::<CssPreset>::new(),
+            ignore_case = true
+        )]
+        css: Option<CssPreset>,
+
+        #[arg(
+            help = t!("help.opt_utility"),
+            short = 'u',
+            long = "utility",
+            value_name = "UTILITY",
+            value_parser = EnumValueParser::<UtilityCss>::new(),
+            ignore_case = true
+        )]
+        utility: Option<UtilityCss>,
+
+        #[arg(
+            help = t!("help.opt_state"),
+            short = 's',
+            long = "state",
+            value_name = "STATE",
+            value_parser = EnumValueParser::<StateManagement>::new(),
+            ignore_case = true
+        )]
+        state: Option<StateManagement>,
+
+        #[arg(
+            help = t!("help.opt_ui"),
+            long = "ui",
+            value_name = "UI",
+            value_parser = EnumValueParser::<UIDesign>::new(),
+            ignore_case = true
+        )]
+        ui: Option<UIDesign>,
+
+        #[arg(
+            help = t!("help.opt_pm"),
+            long = "pm",
+            value_name = "PM",
+            value_parser = EnumValueParser::<PackageManager>::new(),
+            ignore_case = true
+        )]
+        pm: Option<PackageManager>,
+
+        #[arg(help = t!("help.opt_no_install"), long = "no-install")]
+        no_install: bool,
+
+        #[arg(help = t!("help.opt_latest"), long = "latest")]
+        latest: bool,
+
+        #[arg(help = t!("help.opt_registry"), long = "registry", value_name = "URL")]
+        registry: Option<String>,
+
+        #[arg(help = t!("help.opt_from"), long = "from", value_name = "SOURCE")]
+        from: Option<String>,
+
+        #[arg(help = t!("help.opt_dry_run"), long = "dry-run")]
+        dry_run: bool,
+
+        #[arg(help = t!("help.opt_yes"), short = 'y', long = "yes")]
+        yes: bool,
+    },
+    // 为当前项目添加功能
+    #[command(override_help = ADD_HELP.as_str())] Add {
+        #[arg(
+            help = t!("help.add_loader"),
+            long = "loader",
+            value_parser = EnumValueParser::<JsLoader>::new(),
+            ignore_case = true
+        )]
+        loader: Option<JsLoader>,
+
+        #[arg(
+            help = t!("help.opt_css"),
+            short = 'c',
+            long = "css",
+            value_parser = EnumValueParser::<CssPreset>::new(),
+            ignore_case = true
+        )]
+        css: Option<CssPreset>,
+
+        #[arg(
+            help = t!("help.opt_utility"),
+            short = 'u',
+            long = "utility",
+            value_parser = EnumValueParser::<UtilityCss>::new(),
+            ignore_case = true
+        )]
+        utility: Option<UtilityCss>,
+
+        #[arg(
+            help = t!("help.opt_state"),
+            short = 's',
+            long = "state",
+            value_parser = EnumValueParser::<StateManagement>::new(),
+            ignore_case = true
+        )]
+        state: Option<StateManagement>,
+
+        #[arg(
+            help = t!("help.opt_ui"),
+            long = "ui",
+            value_parser = EnumValueParser::<UIDesign>::new(),
+            ignore_case = true
+        )]
+        ui: Option<UIDesign>,
+
+        #[arg(help = t!("help.add_force"), long = "force")]
+        force: bool,
+    },
+    // 升级项目模板
+    #[command(override_help = UPGRADE_HELP.as_str())] Upgrade,
+    // 生成页面或组件
+    #[command(override_help = GENERATE_HELP.as_str(), visible_alias = "g")] Generate {
+        #[arg(help = t!("help.generate_type"), value_parser = EnumValueParser::<GenerateKind>::new(), ignore_case = true)]
+        kind: GenerateKind,
+
+        #[arg(help = t!("help.generate_name"))]
+        name: String,
+    },
+    // 检查环境
+    #[command(override_help = DOCTOR_HELP.as_str())] Doctor,
+    // 列出支持的模板组合
+    #[command(override_help = LIST_HELP.as_str())] List {
+        #[arg(help = t!("help.list_json"), long = "json")]
+        json: bool,
+    },
+    // 模板相关操作
+    #[command(override_help = TEMPLATE_HELP.as_str())] Template {
+        #[command(subcommand)]
+        command: TemplateCommands,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum TemplateCommands {
+    // 导出内置模板
+    #[command(override_help = TEMPLATE_HELP.as_str())] Eject {
+        #[arg(help = t!("help.template_layer"))]
+        layer: Option<String>,
+
+        #[arg(help = t!("help.template_out"), short = 'o', long = "out", value_name = "DIR", value_hint = ValueHint::DirPath)]
+        out: Option<PathBuf>,
+
+        #[arg(
+            help = t!("help.opt_template"),
+            short = 't',
+            long = "template",
+            value_parser = EnumValueParser::<PackTool>::new(),
+            ignore_case = true
+        )]
+        template: Option<PackTool>,
+
+        #[arg(
+            help = t!("help.opt_frame"),
+            short = 'f',
+            long = "frame",
+            value_parser = EnumValueParser::<FrameWork>::new(),
+            ignore_case = true
+        )]
+        frame_work: Option<FrameWork>,
+
+        #[arg(
+            help = t!("help.opt_lang"),
+            short = 'l',
+            long = "lang",
+            value_parser = EnumValueParser::<CodeLanguage>::new(),
+            ignore_case = true
+        )]
+        lang: Option<CodeLanguage>,
+
+        #[arg(
+            help = t!("help.opt_loader"),
+            long = "loader",
+            value_parser = EnumValueParser::<JsLoader>::new(),
+            ignore_case = true
+        )]
+        loader: Option<JsLoader>,
+
+        #[arg(
+            help = t!("help.opt_css"),
+            short = 'c',
+            long = "css",
+            value_parser = EnumValueParser::<CssPreset>::new(),
+            ignore_case = true
+        )]
+        css: Option<CssPreset>,
+
+        #[arg(
+            help = t!("help.opt_utility"),
+            short = 'u',
+            long = "utility",
+            value_parser = EnumValueParser::<UtilityCss>::new(),
+            ignore_case = true
+        )]
+        utility: Option<UtilityCss>,
+
+        #[arg(
+            help = t!("help.opt_state"),
+            short = 's',
+            long = "state",
+            value_parser = EnumValueParser::<StateManagement>::new(),
+            ignore_case = true
+        )]
+        state: Option<StateManagement>,
+
+        #[arg(
+            help = t!("help.opt_ui"),
+            long = "ui",
+            value_parser = EnumValueParser::<UIDesign>::new(),
+            ignore_case = true
+        )]
+        ui: Option<UIDesign>,
+
+        #[arg(help = t!("help.opt_yes"), short = 'y', long = "yes")]
+        yes: bool,
     },
 }
 
 fn main() {
-    if let Err(e) = run() {
-        eprintln!("Error: {}", e);
-        std::process::exit(1);
-    }
+    let code = match run() {
+        std::result::Result::Ok(code) => code,
+        Err(e) => {
+            // 输出完整的错误链，退出码由错误分类决定
+            logger::error(&format!("{:#}", e));
+            exit_code(&e)
+        }
+    };
+    std::process::exit(code);
 }
 
-fn run() -> Result<()> {
-    let _cli = match Cli::try_parse_from(std::env::args()) {
+// 返回进程的退出码，由 main 统一退出
+fn run() -> Result<i32> {
+    // Ctrl-C 时清理未完成的项目
+    listen_interrupt()?;
+    let args: Vec<String> = std::env::args().collect();
+    i18n::init(flag_value(&args, "--locale").as_deref());
+    logger::set_colors(
+        flag_value(&args, "--color")
+            .and_then(|value| ColorChoice::from_str(&value, true).ok())
+            .unwrap_or(ColorChoice::Auto)
+    );
+    let _cli = match Cli::try_parse_from(&args) {
         std::result::Result::Ok(cli) => cli,
         Err(e) => {
             // 检查错误类型
-            handle_parse_error(e);
-            return Ok(());
+            return Ok(handle_parse_error(e));
         }
     };
 
+    let log_file = _cli.log_file.clone();
+    logger
+        ::init(LoggerOptions {
+            format: _cli.log_format,
+            quiet: _cli.quiet,
+            verbose: _cli.verbose,
+            log_file: _cli.log_file,
+        })
+        .with_context(|| {
+            ElzaError::Io(t!("logger.open_file_failed", path = format!("{:?}", log_file.unwrap_or_default())))
+        })?;
     match _cli.command {
         // 如果匹配到了字段
         Some(command) => {
             match command {
-                Commands::Create { name, template, frame_work } => {
+                Commands::Create { name, template, frame_work, lang, loader, css, utility, state, ui, pm, no_install, latest, registry, from, dry_run, yes } => {
                     // 执行创建项目的逻辑
                     match name {
                         Some(project_name) => {
                             let rt: Runtime = Runtime::new()?;
                             rt.block_on(async {
-                                create_project(project_name, template,frame_work).await?;
+                                create_project(project_name, CreateOptions {
+                                    template,
+                                    frame_work,
+                                    lang,
+                                    loader,
+                                    css,
+                                    utility,
+                                    state,
+                                    ui,
+                                    pm,
+                                    install: !no_install,
+                                    latest,
+                                    registry,
+                                    from,
+                                    dry_run,
+                                    yes,
+                                }).await?;
                                 Ok(())
                             })?;
                         }
                         None => {
-                            logger::error("Name为必填参数\n");
+                            logger::error(&format!("{}\n", t!("cli.name_required")));
                             print_custom_help();
                         }
                     }
                 }
+                Commands::Add { loader, css, utility, state, ui, force } => {
+                    add_features(std::path::Path::new("."), AddOptions {
+                        loader,
+                        css,
+                        utility,
+                        state,
+                        ui,
+                        force,
+                    })?;
+                }
+                Commands::Upgrade => {
+                    upgrade_project(std::path::Path::new("."))?;
+                }
+                Commands::Doctor => {
+                    let rt: Runtime = Runtime::new()?;
+                    rt.block_on(doctor(std::path::Path::new(".")))?;
+                }
+                Commands::List { json } => {
+                    list_templates(json)?;
+                }
+                Commands::Generate { kind, name } => {
+                    generate(std::path::Path::new("."), kind, &name)?;
+                }
+                Commands::Template { command: TemplateCommands::Eject { layer, out, template, frame_work, lang, loader, css, utility, state, ui, yes } } => {
+                    match layer {
+                        Some(layer) => eject_layer(&layer, out)?,
+                        None => {
+                            let config = select_config(&CreateOptions {
+                                template,
+                                frame_work,
+                                lang,
+                                loader,
+                                css,
+                                utility,
+                                state,
+                                ui,
+                                yes,
+                                ..Default::default()
+                            })?;
+                            eject_project(config, out)?;
+                        }
+                    }
+                }
             }
         }
         None => {
             print_custom_help();
         }
     }
-    Ok(())
+    Ok(0)
 }
 
 // 处理错误
-fn handle_parse_error(e: clap::Error) {
+fn handle_parse_error(e: clap::Error) -> i32 {
     match e.kind() {
         ErrorKind::InvalidValue => {
-            logger::error("提供的参数值无效\n");
+            logger::error(&format!("{}\n", t!("cli.invalid_value")));
             print_custom_help();
         }
         ErrorKind::UnknownArgument => {
-            logger::error("提供的参数值未知\n");
+            logger::error(&format!("{}\n", t!("cli.unknown_argument")));
             print_custom_help();
         }
         ErrorKind::MissingRequiredArgument => {
-            logger::error("缺少必需参数\n");
+            logger::error(&format!("{}\n", t!("cli.missing_argument")));
             print_custom_help();
         }
+        // 子命令各自通过 override_help 指定帮助信息
         ErrorKind::DisplayHelp => {
-            print_custom_help();
+            let _ = e.print();
         }
         // 其他错误，显示默认错误信息
         _ => {
-            e.print().unwrap();
+            let _ = e.print();
         }
     }
-    std::process::exit(1);
+    // 显示帮助时为 0，参数错误为 2，与 ElzaError::InvalidCombination 的退出码一致
+    e.exit_code()
 }
 
-// 获取模板可能的值
-fn get_possible_template_values() -> String {
-    let mut possible_values = Vec::new();
-    for template in PackTool::value_variants() {
-        possible_values.push(template.to_string().to_lowercase());
-    }
-    let joined_strings = possible_values.join(",");
-    joined_strings
+// 获取参数可能的值
+fn get_possible_values<T: ValueEnum>() -> String {
+    let values = T::value_variants()
+        .iter()
+        .filter_map(|variant| variant.to_possible_value())
+        .map(|value| value.get_name().to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+    t!("help.possible_values", values = values)
 }
 
-// 获取框架可能的值
-fn get_possible_frame_values() -> String {
-    let mut possible_values = Vec::new();
-    for frame_work in FrameWork::value_variants() {
-        possible_values.push(frame_work.to_string().to_lowercase());
+// 在解析参数前读取影响帮助信息的参数，支持 --locale en 与 --locale=en 两种写法
+fn flag_value(args: &[String], flag: &str) -> Option<String> {
+    let mut iter = args.iter().skip(1);
+    while let Some(arg) = iter.next() {
+        if arg == "--" {
+            break;
+        }
+        if arg == flag {
+            return iter.next().cloned();
+        }
+        if let Some(value) = arg.strip_prefix(flag).and_then(|rest| rest.strip_prefix('=')) {
+            return Some(value.to_string());
+        }
     }
-    let joined_strings = possible_values.join(",");
-    joined_strings
+    None
 }
 
 // 输出自定义帮助信息
@@ -1,25 +1,67 @@
-use std::process::exit;
-use anyhow::Result;
-use crate::utils::logger;
+use std::fmt;
+use std::io;
 
-// 通用错误处理函数
-pub fn handle_option<T>(option: Option<T>, error_msg: &str) -> T {
-    match option {
-        Some(value) => value,
-        None => {
-            logger::error(error_msg);
-            exit(1);
+// cli 的错误分类，以 anyhow context 的形式附加在错误链上，main 据此决定退出码
+#[derive(Debug)]
+pub enum ElzaError {
+    // 文件或目录读写失败
+    Io(String),
+    // 模板、模板清单或模板中的 package.json 无效
+    Template(String),
+    // 请求镜像源失败
+    Registry(String),
+    // git 命令执行失败
+    Git(String),
+    // 用户取消了交互选择
+    PromptCancelled,
+    // 不支持的选项组合
+    InvalidCombination(String),
+    // 本地修改与模板改动冲突，需要手动处理
+    Conflict(String),
+    // 缺少 npm、tar 等外部命令，依赖安装失败或环境检查未通过
+    Environment(String),
+}
+
+impl ElzaError {
+    // 2 与 clap 的参数错误一致，130 与 Ctrl-C 退出一致，其余未分类的错误为 1
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            ElzaError::InvalidCombination(_) => 2,
+            ElzaError::Io(_) => 3,
+            ElzaError::Template(_) => 4,
+            ElzaError::Registry(_) => 5,
+            ElzaError::Git(_) => 6,
+            ElzaError::Conflict(_) => 7,
+            ElzaError::Environment(_) => 8,
+            ElzaError::PromptCancelled => 130,
         }
     }
 }
 
-// 错误处理结果
-pub fn handle_result<T, E: std::fmt::Debug>(result: Result<T, E>, error_msg: &str) -> T {
-    match result {
-        Ok(value) => value,
-        Err(err) => {
-            logger::error(&format!("{}: {:?}", error_msg, err));
-            exit(1);
+impl fmt::Display for ElzaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ElzaError::Io(msg)
+            | ElzaError::Template(msg)
+            | ElzaError::Registry(msg)
+            | ElzaError::Git(msg)
+            | ElzaError::Conflict(msg)
+            | ElzaError::Environment(msg)
+            | ElzaError::InvalidCombination(msg) => write!(f, "{}", msg),
+            ElzaError::PromptCancelled => write!(f, "{}", t!("common.cancelled")),
+        }
+    }
+}
+
+impl std::error::Error for ElzaError {}
+
+// 取错误链上最外层的分类，未分类的 io 错误同样视为读写失败
+pub fn exit_code(error: &anyhow::Error) -> i32 {
+    match error.downcast_ref::<ElzaError>() {
+        Some(e) => e.exit_code(),
+        None if error.chain().any(|cause| cause.is::<io::Error>()) => {
+            ElzaError::Io(String::new()).exit_code()
         }
+        None => 1,
     }
 }
@@ -0,0 +1,112 @@
+use serde_json::{Map, Value};
+use std::env;
+use std::sync::OnceLock;
+
+// 消息目录，键为消息 id，值中的 {name} 为占位符
+const ZH_CN: &str = include_str!("../../locales/zh-CN.json");
+const EN_US: &str = include_str!("../../locales/en-US.json");
+
+// 指定输出语言的环境变量，优先级低于 --locale 参数
+pub const LOCALE_ENV: &str = "ELZA_LANG";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Locale {
+    ZhCn,
+    EnUs,
+}
+
+impl Locale {
+    // 支持 zh-CN、zh_CN.UTF-8、en、en_US.UTF-8 等写法，无法识别时返回 None
+    pub fn parse(value: &str) -> Option<Self> {
+        let lang = value
+            .split(['.', '@'])
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase()
+            .replace('_', "-");
+        if lang.starts_with("zh") {
+            Some(Locale::ZhCn)
+        } else if lang.starts_with("en") {
+            Some(Locale::EnUs)
+        } else {
+            None
+        }
+    }
+}
+
+struct Catalog {
+    messages: Map<String, Value>,
+    // 目标语言缺少的消息回退到中文
+    fallback: Map<String, Value>,
+}
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+// 确定输出语言：--locale 参数、ELZA_LANG、系统的 LC_ALL/LC_MESSAGES/LANG，默认中文
+pub fn init(flag: Option<&str>) {
+    let _ = CATALOG.set(Catalog::new(detect_locale(flag)));
+}
+
+fn detect_locale(flag: Option<&str>) -> Locale {
+    flag.and_then(Locale::parse)
+        .or_else(|| env_locale(LOCALE_ENV))
+        .or_else(|| {
+            ["LC_ALL", "LC_MESSAGES", "LANG"]
+                .iter()
+                .find_map(|name| env_locale(name))
+        })
+        .unwrap_or(Locale::ZhCn)
+}
+
+fn env_locale(name: &str) -> Option<Locale> {
+    env::var(name)
+        .ok()
+        .filter(|value| !value.is_empty())
+        .and_then(|value| Locale::parse(&value))
+}
+
+impl Catalog {
+    fn new(locale: Locale) -> Self {
+        let fallback = parse_messages(ZH_CN);
+        let messages = match locale {
+            Locale::ZhCn => fallback.clone(),
+            Locale::EnUs => parse_messages(EN_US),
+        };
+        Self { messages, fallback }
+    }
+}
+
+fn parse_messages(content: &str) -> Map<String, Value> {
+    serde_json::from_str(content).expect("消息目录格式错误")
+}
+
+// 未调用 init 时按环境变量确定语言
+fn catalog() -> &'static Catalog {
+    CATALOG.get_or_init(|| Catalog::new(detect_locale(None)))
+}
+
+// 查找消息并替换占位符，目录中不存在的 id 原样返回
+pub fn translate(key: &str, args: &[(&str, String)]) -> String {
+    let catalog = catalog();
+    let template = catalog
+        .messages
+        .get(key)
+        .or_else(|| catalog.fallback.get(key))
+        .and_then(Value::as_str)
+        .unwrap_or(key);
+    let mut message = template.to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), value);
+    }
+    message
+}
+
+// t!("create.exists", name = project_name)
+macro_rules! t {
+    ($key:expr) => {
+        $crate::utils::i18n::translate($key, &[])
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::utils::i18n::translate($key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
@@ -1,29 +1,246 @@
-use console::style;
+use clap::ValueEnum;
+use console::{style, StyledObject};
+use serde_json::{json, Map, Value};
+use std::env;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::sync::{Mutex, OnceLock};
+
+// 指定日志级别的环境变量，优先级低于 --quiet、--verbose
+pub const LEVEL_ENV: &str = "ELZA_LOG";
+
+// 日志输出格式，json 时每条日志输出一行 JSON，便于编辑器插件等工具解析进度
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum LogFormat {
+    Text,
+    Json,
+}
+
+// 日志级别，只输出不低于当前级别的日志
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, ValueEnum)]
+pub enum Level {
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+#[derive(Debug, Default)]
+pub struct LoggerOptions {
+    pub format: Option<LogFormat>,
+    pub quiet: bool,
+    pub verbose: bool,
+    pub log_file: Option<PathBuf>,
+}
+
+struct Logger {
+    format: LogFormat,
+    level: Level,
+    // 日志文件记录所有级别的日志，不含颜色
+    file: Option<Mutex<File>>,
+}
+
+static LOGGER: OnceLock<Logger> = OnceLock::new();
+
+// 初始化日志配置，未初始化时按文本格式输出 info 及以上级别的日志
+pub fn init(options: LoggerOptions) -> std::io::Result<()> {
+    let format = options.format.unwrap_or(LogFormat::Text);
+    // json 格式面向工具，默认输出全部事件
+    let default_level = match format {
+        LogFormat::Text => Level::Info,
+        LogFormat::Json => Level::Debug,
+    };
+    let level = if options.verbose {
+        Level::Debug
+    } else if options.quiet {
+        Level::Warn
+    } else {
+        env::var(LEVEL_ENV)
+            .ok()
+            .and_then(|value| Level::from_str(value.trim(), true).ok())
+            .unwrap_or(default_level)
+    };
+    let file = match options.log_file {
+        Some(path) => Some(Mutex::new(
+            OpenOptions::new().create(true).append(true).open(path)?,
+        )),
+        None => None,
+    };
+    let _ = LOGGER.set(Logger {
+        format,
+        level,
+        file,
+    });
+    Ok(())
+}
+
+// NO_COLOR 仅在 auto 时生效，always 时强制输出颜色
+pub fn set_colors(choice: ColorChoice) {
+    let no_color = env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    let enabled = match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto if no_color => false,
+        ColorChoice::Auto => return,
+    };
+    console::set_colors_enabled(enabled);
+    console::set_colors_enabled_stderr(enabled);
+}
+
+pub fn is_json() -> bool {
+    LOGGER
+        .get()
+        .is_some_and(|logger| logger.format == LogFormat::Json)
+}
+
+fn enabled(level: Level) -> bool {
+    level >= LOGGER.get().map_or(Level::Info, |logger| logger.level)
+}
+
+fn write_file(label: &str, msg: &str) {
+    let Some(file) = LOGGER.get().and_then(|logger| logger.file.as_ref()) else {
+        return;
+    };
+    if let Ok(mut file) = file.lock() {
+        let _ = writeln!(
+            file,
+            "{} - {}",
+            console::strip_ansi_codes(label),
+            console::strip_ansi_codes(msg)
+        );
+    }
+}
+
+// 文本格式下输出带标签的一行，error 输出到 stderr
+fn text(level: Level, label: StyledObject<&str>, padding: &str, msg: &str) {
+    write_file(&label.to_string(), msg);
+    if !enabled(level) {
+        return;
+    }
+    if level == Level::Error {
+        eprintln!("{}{} - {}", label.for_stderr(), padding, msg);
+    } else {
+        println!("{}{} - {}", label, padding, msg);
+    }
+}
+
+// json 格式下输出 type、level、message 及附带的字段，type 未指定时与 level 相同
+fn emit(level: Level, label: &str, kind: Option<&str>, msg: &str, data: Value) {
+    write_file(label, msg);
+    if !enabled(level) {
+        return;
+    }
+    let mut object = Map::new();
+    object.insert("type".to_string(), json!(kind.unwrap_or(label)));
+    object.insert("level".to_string(), json!(label));
+    object.insert("message".to_string(), json!(console::strip_ansi_codes(msg)));
+    if let Value::Object(fields) = data {
+        object.extend(fields);
+    }
+    if level == Level::Error {
+        eprintln!("{}", Value::Object(object));
+    } else {
+        println!("{}", Value::Object(object));
+    }
+}
 
 pub fn error(msg: &str) {
-    println!("{} - {}", style("error").red(), msg);
+    if is_json() {
+        return emit(Level::Error, "error", None, msg, Value::Null);
+    }
+    text(Level::Error, style("error").red(), "", msg);
 }
 
 pub fn info(msg: &str) {
-    println!("{}  - {}", style("info").cyan(), msg)
+    if is_json() {
+        return emit(Level::Info, "info", None, msg, Value::Null);
+    }
+    text(Level::Info, style("info").cyan(), " ", msg);
 }
 
 pub fn ready(msg: &str) {
-    println!("{} - {}", style("ready").green(), msg);
+    if is_json() {
+        return emit(Level::Info, "ready", None, msg, Value::Null);
+    }
+    text(Level::Info, style("ready").green(), "", msg);
 }
 
+// 交互提示不受日志级别影响，json 格式下与选择列表一样输出到 stderr
 pub fn pick(msg: &str) {
+    write_file("pick", msg);
+    if is_json() {
+        return eprintln!("{}  - {}", style("pick").color256(69).for_stderr(), msg);
+    }
     println!("{}  - {}", style("pick").color256(69), msg);
 }
 
 pub fn event(msg: &str) {
-    println!("{} - {}", style("event").magenta(), msg);
+    if is_json() {
+        return emit(Level::Info, "event", None, msg, Value::Null);
+    }
+    text(Level::Info, style("event").magenta(), "", msg);
 }
 
 pub fn full_info(msg: &str) {
-    println!("{}  - {}", style("info").cyan(), style(msg).yellow());
+    if is_json() {
+        return emit(Level::Info, "info", None, msg, Value::Null);
+    }
+    let msg = style(msg).yellow().to_string();
+    text(Level::Info, style("info").cyan(), " ", &msg);
 }
 
 pub fn warning(msg: &str) {
-    println!("{}  - {}", style("warn").yellow(), msg);
-}
\ No newline at end of file
+    if is_json() {
+        return emit(Level::Warn, "warning", None, msg, Value::Null);
+    }
+    text(Level::Warn, style("warn").yellow(), " ", msg);
+}
+
+// 逐个文件、依赖的进度及排查问题用的细节，默认不输出
+pub fn debug(msg: &str) {
+    if is_json() {
+        return emit(Level::Debug, "debug", None, msg, Value::Null);
+    }
+    text(Level::Debug, style("debug").dim(), "", msg);
+}
+
+// 附带结构化数据的日志，文本格式下按 fallback 输出，json 格式下 kind 作为 type，data 中的字段一并输出
+fn with_data(level: Level, label: &str, kind: &str, msg: &str, data: Value, fallback: fn(&str)) {
+    if is_json() {
+        return emit(level, label, Some(kind), msg, data);
+    }
+    fallback(msg);
+}
+
+pub fn record(kind: &str, msg: &str, data: Value) {
+    with_data(Level::Debug, "event", kind, msg, data, debug);
+}
+
+pub fn record_info(kind: &str, msg: &str, data: Value) {
+    with_data(Level::Info, "info", kind, msg, data, info);
+}
+
+// 最终结果，包含项目路径及生成配置
+pub fn summary(msg: &str, data: Value) {
+    with_data(Level::Info, "ready", "summary", msg, data, ready);
+}
+
+// 更新提醒等提示信息原样输出，json 格式下输出到 stderr，避免混入事件流
+pub fn notice(msg: &str) {
+    write_file("info", msg);
+    if !enabled(Level::Info) {
+        return;
+    }
+    if is_json() {
+        return eprintln!("{}", msg);
+    }
+    println!("{}", msg);
+}
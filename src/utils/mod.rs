@@ -1,3 +1,8 @@
+#[macro_use]
+pub mod i18n;
 pub mod logger;
 pub mod error;
-pub mod utils;
\ No newline at end of file
+#[allow(clippy::module_inception)]
+pub mod utils;
+#[cfg(test)]
+pub mod test_utils;
@@ -1,12 +1,14 @@
 use std::fs::File;
-use std::io::{ BufReader, BufRead };
+use std::io::{ BufReader, BufRead, ErrorKind };
+use std::path::PathBuf;
 use reqwest::Client;
-use console::style;
+use console::{ measure_text_width, style };
 use anyhow::{ Context, Result };
 
+use super::error::ElzaError;
 use super::logger;
 
-const DEFAULT_NPM_REGISTRY: &str = "https://registry.npmjs.org/";
+pub const DEFAULT_NPM_REGISTRY: &str = "https://registry.npmjs.org/";
 
 struct Borders<'a> {
     tl: &'a str,
@@ -17,24 +19,29 @@ struct Borders<'a> {
     v: &'a str,
 }
 
+// 用户级 npm 配置文件路径，找不到home目录时返回 None
+pub fn npmrc_path() -> Option<PathBuf> {
+    dirs::home_dir().map(|home_dir| home_dir.join(".npmrc"))
+}
+
 pub fn get_user_npm_registry() -> String {
-    // 获取home目录
-    let home_dir = match dirs::home_dir() {
+    let npmrc_path = match npmrc_path() {
         Some(path) => path,
         None => {
-            logger::warning("未找到home目录");
+            logger::warning(&t!("npmrc.no_home", registry = DEFAULT_NPM_REGISTRY));
             return DEFAULT_NPM_REGISTRY.to_string();
         }
     };
 
-    // npm 配置文件路径
-    let npmrc_path = home_dir.join(".npmrc");
-
-    // 打开配置文件
+    // 打开配置文件，未配置 .npmrc 属于正常情况
     let file = match File::open(&npmrc_path) {
         Ok(file) => file,
-        Err(_) => {
-            logger::warning("打开.npmrc配置文件失败");
+        Err(e) if e.kind() == ErrorKind::NotFound => {
+            logger::debug(&t!("npmrc.not_found", registry = DEFAULT_NPM_REGISTRY));
+            return DEFAULT_NPM_REGISTRY.to_string();
+        }
+        Err(e) => {
+            logger::warning(&t!("npmrc.open_failed", error = e, registry = DEFAULT_NPM_REGISTRY));
             return DEFAULT_NPM_REGISTRY.to_string();
         }
     };
@@ -42,11 +49,10 @@ pub fn get_user_npm_registry() -> String {
     // 读取配置文件中的镜像设置
     let reader = BufReader::new(file);
 
-    for line in reader.lines() {
-        if let Ok(line) = line {
-            if line.starts_with("registry=") {
-                let registry_url = line.trim_start_matches("registry=").trim();
-                return registry_url.to_string();
+    for line in reader.lines().map_while(Result::ok) {
+        if let Some((key, value)) = line.split_once('=') {
+            if key.trim() == "registry" {
+                return value.trim().to_string();
             }
         }
     }
@@ -59,19 +65,34 @@ pub async fn get_latest_version(name: &str) -> Result<String> {
     // 创建一个 reqwest 客户端
     let client = Client::new();
     let npm_registry = get_user_npm_registry();
+    fetch_latest_version(&client, &npm_registry, name).await
+}
+
+// 从指定镜像源的 dist-tags 中获取最新版本，请求失败时返回错误，包不存在等非成功状态时返回空字符串
+pub async fn fetch_latest_version(client: &Client, registry: &str, name: &str) -> Result<String> {
+    let registry = registry.trim_end_matches('/');
+    // scope 包名中的 / 需要转义
+    let url = format!("{}/{}", registry, name.replace('/', "%2F"));
+    logger::debug(&t!("registry.request", url = url));
     let response = client
-        .get(format!("{}{}", npm_registry, name))
+        .get(&url)
+        // 精简版元数据，体积远小于完整的包信息
+        .header("Accept", "application/vnd.npm.install-v1+json")
         .send().await
-        .context(format!("error sending request url ({}{})", npm_registry, name))?;
+        .with_context(|| ElzaError::Registry(t!("registry.request_failed", url = url)))?;
     // 检查请求是否成功
     if response.status().is_success() {
-        let body = response.text().await?;
+        let body = response
+            .text().await
+            .with_context(|| ElzaError::Registry(t!("registry.read_failed", url = url)))?;
         // 解析 JSON 响应，获取版本信息等
-        let package_info: serde_json::Value = serde_json::from_str(&body)?;
+        let package_info: serde_json::Value = serde_json
+            ::from_str(&body)
+            .with_context(|| ElzaError::Registry(t!("registry.parse_failed", url = url)))?;
         let latest_version = package_info["dist-tags"]["latest"].as_str().unwrap_or("");
-        return anyhow::Ok(latest_version.to_string());
+        anyhow::Ok(latest_version.to_string())
     } else {
-        return anyhow::Ok("".to_string());
+        anyhow::Ok("".to_string())
     }
 }
 
@@ -104,20 +125,24 @@ fn print_version(current_version: &str, latest_version: &str) {
         h: &format!("{}", style("─").yellow()),
         v: &format!("{}", style("│").yellow()),
     };
-    let header = format!("{}", style("  发现新版本!  ").green());
-    let footer = format!("  请使用 `{}` 更新  ", style("npm install -g elza-cli").magenta());
+    let header = format!("{}", style(format!("  {}  ", t!("update.header"))).green());
+    let footer = format!(
+        "  {}  ",
+        t!("update.footer", command = style("npm install -g elza-cli").magenta())
+    );
     let current_version_text = format!(
-        "   > 当前版本: {}  ",
-        style(format!("v{}", current_version)).red()
+        "   > {}  ",
+        t!("update.current", version = style(format!("v{}", current_version)).red())
     );
     let latest_version_text = format!(
-        "   > 最新版本: {}  ",
-        style(format!("v{}", latest_version)).green().bold()
+        "   > {}  ",
+        t!("update.latest", version = style(format!("v{}", latest_version)).green().bold())
     );
-    let header_len = get_string_length(&header) - 9;
-    let footer_len = get_string_length(&footer) - 9;
-    let current_len = get_string_length(&current_version_text) - 9;
-    let latest_len = get_string_length(&latest_version_text) - 13;
+    // 按显示宽度计算，忽略颜色转义序列，中文占两列
+    let header_len = measure_text_width(&header);
+    let footer_len = measure_text_width(&footer);
+    let current_len = measure_text_width(&current_version_text);
+    let latest_len = measure_text_width(&latest_version_text);
     let max_len = header_len.max(footer_len).max(current_len).max(latest_len);
     let before_lines = vec![
         format!("{}{}{}", borders.tl, borders.h.repeat(max_len), borders.tr),
@@ -152,27 +177,8 @@ fn print_version(current_version: &str, latest_version: &str) {
     }
     let before = before.join("\n");
     let after = after.join("\n");
-    println!("{}", before);
-    logger::info(&main);
-    println!("{}", after);
-}
-
-// 获取字符串长度
-fn get_string_length(s: &str) -> usize {
-    let s = String::from(s);
-    let vec: Vec<char> = s.chars().collect();
-    let mut len = 0;
-    for c in vec {
-        if is_chinese(c) {
-            len += 2;
-        } else {
-            len += 1;
-        }
-    }
-    len
-}
-
-// 是否为中文字符
-fn is_chinese(c: char) -> bool {
-    return c >= '\u{4e00}' && c <= '\u{9fa5}';
+    // json 格式下整个提醒输出到 stderr
+    logger::notice(&before);
+    logger::notice(&format!("{}  - {}", style("info").cyan(), main));
+    logger::notice(&after);
 }
@@ -0,0 +1,43 @@
+import React from 'react';
+import { createRoot } from 'react-dom/client';
+import Router from './router';
+{% if state == redux %}
+import { Provider } from 'react-redux';
+import { store } from './store';
+{% end %}
+{% if uiStyle %}
+import '{%= uiStyle %}';
+{% end %}
+{% if ui == antd %}
+import { ConfigProvider } from 'antd';
+import theme from './theme';
+{% end %}
+{% if utility != none %}
+import './global.css';
+{% end %}
+
+const rootEl = document.getElementById('root');
+if (rootEl) {
+  const root = createRoot(rootEl);
+  root.render(
+    <React.StrictMode>
+{% if ui == antd %}
+      <ConfigProvider theme={theme}>
+{% if state == redux %}
+        <Provider store={store}>
+          <Router />
+        </Provider>
+{% else %}
+        <Router />
+{% end %}
+      </ConfigProvider>
+{% elif state == redux %}
+      <Provider store={store}>
+        <Router />
+      </Provider>
+{% else %}
+      <Router />
+{% end %}
+    </React.StrictMode>,
+  );
+}
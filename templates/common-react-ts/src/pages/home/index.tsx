@@ -0,0 +1,32 @@
+import React from 'react';
+{% if ui == antd %}
+import { Button } from 'antd';
+{% elif ui == arco %}
+import { Button } from '@arco-design/web-react';
+{% elif ui == mui %}
+import { Button } from '@mui/material';
+{% end %}
+{% if styledPackage %}
+import styled from '{%= styledPackage %}';
+
+const Title = styled.h1`
+  color: #1677ff;
+`;
+{% end %}
+
+export default function Home() {
+  return (
+    <div>
+{% if styledPackage %}
+      <Title>This is Home Page</Title>
+{% else %}
+      <h1>This is Home Page</h1>
+{% end %}
+{% if ui == antd or ui == arco %}
+      <Button type="primary">Get Started</Button>
+{% elif ui == mui %}
+      <Button variant="contained">Get Started</Button>
+{% end %}
+    </div>
+  );
+}
\ No newline at end of file
@@ -0,0 +1,11 @@
+import type { ThemeConfig } from 'antd';
+
+// antd 主题配置，详见 https://ant.design/docs/react/customize-theme-cn
+const theme: ThemeConfig = {
+  token: {
+    colorPrimary: '#1677ff',
+    borderRadius: 6,
+  },
+};
+
+export default theme;
@@ -0,0 +1,8 @@
+import { createApp } from 'vue';
+import App from './App.vue';
+import router from './router';
+{% if utility != none %}
+import './global.css';
+{% end %}
+
+createApp(App).use(router).mount('#root');
@@ -0,0 +1,12 @@
+import { createRouter, createWebHashHistory } from 'vue-router';
+
+const routes = [
+  { path: '/', redirect: '/home' },
+  { path: '/home', component: () => import('../pages/home/index.vue') },
+  { path: '/:pathMatch(.*)*', redirect: '/home' },
+];
+
+export default createRouter({
+  history: createWebHashHistory(),
+  routes,
+});
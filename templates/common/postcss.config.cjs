@@ -0,0 +1,10 @@
+module.exports = {
+  plugins: {
+{% if utility == tailwind %}
+    tailwindcss: {},
+{% elif utility == unocss %}
+    '@unocss/postcss': {},
+{% end %}
+    autoprefixer: {},
+  },
+};
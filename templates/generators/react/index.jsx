@@ -0,0 +1,46 @@
+import React from 'react';
+{% if styledPackage %}
+import styled from '{%= styledPackage %}';
+
+const Container = styled.div`
+  padding: 16px;
+`;
+{% elif cssModule %}
+import styles from './{%= styleFile %}';
+{% else %}
+import './{%= styleFile %}';
+{% end %}
+{% if kind == component and lang == ts %}
+
+interface {%= componentName %}Props {
+  children?: React.ReactNode;
+}
+{% end %}
+
+{% if kind == page %}
+export default function {%= componentName %}() {
+{% elif lang == ts %}
+export default function {%= componentName %}({ children }: {%= componentName %}Props) {
+{% else %}
+export default function {%= componentName %}({ children }) {
+{% end %}
+  return (
+{% if styledPackage %}
+    <Container>
+{% elif cssModule %}
+    <div className={styles.container}>
+{% else %}
+    <div className="{%= className %}">
+{% end %}
+{% if kind == page %}
+      <h1>This is {%= componentName %} Page</h1>
+{% else %}
+      {children}
+{% end %}
+{% if styledPackage %}
+    </Container>
+{% else %}
+    </div>
+{% end %}
+  );
+}
@@ -0,0 +1,41 @@
+import { defineConfig } from '@farmfe/core';
+{% for item in imports %}
+{%= item %}
+{% end %}
+import farmAutoRoutes from 'farm-plugin-auto-routes';
+
+export default defineConfig({
+{% if swcPlugin or uiTransform %}
+  compilation: {
+    script: {
+      plugins: [
+{% if swcPlugin %}
+        {
+          name: '{%= swcPlugin %}',
+          options: {},
+          filters: {
+            moduleTypes: ['tsx', 'jsx'],
+          },
+        },
+{% end %}
+{% if uiTransform %}
+        {
+          name: '@swc/plugin-transform-imports',
+          options: {%= uiTransform %},
+          filters: {
+            moduleTypes: ['tsx', 'jsx'],
+          },
+        },
+{% end %}
+      ],
+    },
+  },
+{% end %}
+  plugins: [
+    '@farmfe/plugin-react',
+{% for plugin in plugins %}
+    {%= plugin %},
+{% end %}
+    farmAutoRoutes({ writeToDisk: true }),
+  ],
+});
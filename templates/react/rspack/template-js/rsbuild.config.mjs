@@ -0,0 +1,34 @@
+import { defineConfig } from '@rsbuild/core';
+import { pluginReact } from '@rsbuild/plugin-react';
+{% for item in imports %}
+{%= item %}
+{% end %}
+import AutoRoutesPlugin from 'webpack-plugin-auto-routes';
+
+export default defineConfig({
+{% if rsbuildImport %}
+  source: {
+    transformImport: [{%= rsbuildImport %}],
+  },
+{% end %}
+  html: {
+    template: './public/index.html',
+  },
+  tools: {
+{% if css == emotion %}
+    swc: {
+      jsc: {
+        experimental: {
+          plugins: [['@swc/plugin-emotion', {}]],
+        },
+      },
+    },
+{% end %}
+    rspack: {
+      plugins: [
+        new AutoRoutesPlugin({dir: './src/pages', moduleType: 'jsx'}),
+      ],
+    },
+  },
+  plugins: [{%= plugins | join(', ') %}],
+});
@@ -0,0 +1,11 @@
+import { defineConfig } from 'vite';
+import react from '@vitejs/plugin-react-swc';
+import AutoRoutesPlugin from 'farm-plugin-auto-routes';
+{% if ui == arco %}
+import { vitePluginForArco } from '@arco-plugins/vite-react';
+{% end %}
+
+// https://vitejs.dev/config/
+export default defineConfig({
+  plugins: [react({% if swcPlugin %}{ plugins: [['{%= swcPlugin %}', {}]] }{% end %}), AutoRoutesPlugin({ writeToDisk: true }){% if ui == arco %}, vitePluginForArco({ style: 'css' }){% end %}],
+});
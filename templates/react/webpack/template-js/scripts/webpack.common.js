@@ -7,12 +7,13 @@ const BetterInfoPlugin = require('webpack-plugin-better-info');
 
 const isDev = process.env.NODE_ENV === 'development';
 
+{% if css == sass or css == less %}
 const getStyleLoader = (openCssModule = false) => {
   const loader = [
     isDev ? 'style-loader' : MiniCssExtractPlugin.loader,
     'css-loader',
     'postcss-loader',
-    '`placeholder:0`',
+    '{%= styleLoader %}',
   ];
   if (openCssModule) {
     loader[1] = {
@@ -27,6 +28,7 @@ const getStyleLoader = (openCssModule = false) => {
   return loader;
 };
 
+{% end %}
 const filename = isDev ? '[name].js' : 'static/js/[name].[contenthash:8].js';
 
 module.exports = {
@@ -57,7 +59,7 @@ module.exports = {
       {
         test: /\.(j|t)sx?$/,
         use: {
-          loader: '`placeholder:2`',
+          loader: '{%= loader %}-loader',
         },
         exclude: /node_modules/,
       },
@@ -70,8 +72,9 @@ module.exports = {
         ],
         exclude: /node_modules/,
       },
+{% if css == sass or css == less %}
       {
-        test: /\.`placeholder:1`$/,
+        test: /\.{%= styleExt %}$/,
         oneOf: [
           {
             resourceQuery: /css_modules/,
@@ -83,6 +86,7 @@ module.exports = {
         ],
         exclude: /node_modules/,
       },
+{% end %}
       {
         test: /\.(png|jpe?g|gif|webp|svg|bmp)$/,
         type: 'asset',
@@ -0,0 +1,49 @@
+import React from 'react';
+import { createRoot } from 'react-dom/client';
+import Router from './router';
+{% if state == redux %}
+import { Provider } from 'react-redux';
+import { store } from './store';
+{% end %}
+{% if uiStyle %}
+import '{%= uiStyle %}';
+{% end %}
+{% if ui == antd %}
+import { ConfigProvider } from 'antd';
+import theme from './theme';
+{% end %}
+{% if utility != none %}
+import './global.css';
+{% end %}
+
+const root = createRoot(document.getElementById('root'));
+
+function renderApp() {
+{% if ui == antd %}
+  root.render(
+    <ConfigProvider theme={theme}>
+{% if state == redux %}
+      <Provider store={store}>
+        <Router />
+      </Provider>
+{% else %}
+      <Router />
+{% end %}
+    </ConfigProvider>,
+  );
+{% elif state == redux %}
+  root.render(
+    <Provider store={store}>
+      <Router />
+    </Provider>,
+  );
+{% else %}
+  root.render(<Router />);
+{% end %}
+}
+
+renderApp();
+
+if (module.hot) {
+  module.hot.accept('./router', renderApp);
+}
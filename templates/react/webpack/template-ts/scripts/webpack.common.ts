@@ -15,12 +15,13 @@ type WebpackConfiguration = Configuration & WebpackDevServerConfiguration;
 
 const isDev: boolean = process.env.NODE_ENV === 'development';
 
+{% if css == sass or css == less %}
 const getStyleLoader = (openCssModule = false) => {
   const loader: any = [
     isDev ? 'style-loader' : MiniCssExtractPlugin.loader,
     'css-loader',
     'postcss-loader',
-    '`placeholder:0`',
+    '{%= styleLoader %}',
   ];
   if (openCssModule) {
     loader[1] = {
@@ -35,6 +36,7 @@ const getStyleLoader = (openCssModule = false) => {
   return loader;
 };
 
+{% end %}
 const filename: string = isDev
   ? '[name].js'
   : 'static/js/[name].[chunkhash:8].js';
@@ -67,7 +69,7 @@ const baseConfig: WebpackConfiguration = {
     rules: [
       {
         test: /\.(j|t)sx?$/,
-        use: '`placeholder:2`',
+        use: '{%= loader %}-loader',
         exclude: /node_modules/,
       },
       {
@@ -79,8 +81,9 @@ const baseConfig: WebpackConfiguration = {
         ],
         exclude: /node_modules/,
       },
+{% if css == sass or css == less %}
       {
-        test: /\.`placeholder:1`$/,
+        test: /\.{%= styleExt %}$/,
         oneOf: [
           {
             resourceQuery: /css_modules/,
@@ -92,6 +95,7 @@ const baseConfig: WebpackConfiguration = {
         ],
         exclude: /node_modules/,
       },
+{% end %}
       {
         test: /\.(png|jpe?g|gif|webp|svg)$/,
         type: 'asset',
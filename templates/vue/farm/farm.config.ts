@@ -0,0 +1,10 @@
+import { defineConfig } from '@farmfe/core';
+{% for item in imports %}
+{%= item %}
+{% end %}
+import vue from '@vitejs/plugin-vue';
+
+export default defineConfig({
+  plugins: [{%= plugins | join(', ') %}],
+  vitePlugins: [vue()],
+});
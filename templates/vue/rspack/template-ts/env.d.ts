@@ -0,0 +1 @@
+/// <reference types="@rsbuild/core/types" />
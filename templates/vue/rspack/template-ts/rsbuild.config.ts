@@ -0,0 +1,17 @@
+import { defineConfig } from '@rsbuild/core';
+import { pluginVue } from '@rsbuild/plugin-vue';
+{% for item in imports %}
+{%= item %}
+{% end %}
+
+export default defineConfig({
+  source: {
+    entry: {
+      index: './src/main.ts',
+    },
+  },
+  html: {
+    template: './public/index.html',
+  },
+  plugins: [{%= plugins | join(', ') %}],
+});
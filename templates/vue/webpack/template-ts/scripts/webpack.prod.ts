@@ -0,0 +1,48 @@
+import { merge } from 'webpack-merge';
+import CssMinimizerPlugin from 'css-minimizer-webpack-plugin';
+import TerserPlugin from 'terser-webpack-plugin';
+import common from './webpack.common';
+
+export default merge(common, {
+  mode: 'production',
+  optimization: {
+    minimizer: [
+      new CssMinimizerPlugin(),
+      new TerserPlugin({
+        // 配置css压缩过后，默认js压缩会失效，需要配置
+        parallel: true,
+        terserOptions: {
+          compress: {
+            pure_funcs: ['console.log'], // 删除console.log
+          },
+        },
+      }),
+    ],
+    splitChunks: {
+      cacheGroups: {
+        vue: {
+          test: /[\\/]node_modules[\\/](vue|vue-router|@vue)[\\/]/,
+          name: 'vue',
+          chunks: 'all',
+          priority: -1,
+        },
+        vendors: {
+          // 提取node_modules中的代码
+          test: /[\\/]node_modules[\\/]/,
+          name: 'vendors',
+          minChunks: 1,
+          chunks: 'initial', // 只提取初始化就能获取的模块，忽略异步调用
+          minSize: 0,
+          priority: -2,
+        },
+        commons: {
+          // 提取页面公共代码
+          name: 'commons',
+          minChunks: 2,
+          chunks: 'initial',
+          minSize: 0,
+        },
+      },
+    },
+  },
+});
@@ -0,0 +1,13 @@
+declare module '*.svg';
+declare module '*.png';
+declare module '*.css';
+
+declare module '*.scss' {
+  const content: { [key: string]: string };
+  export = content;
+}
+
+declare module '*.less' {
+  const content: { [key: string]: string };
+  export = content;
+}